@@ -46,8 +46,6 @@ if [[ "${BASH_VERSION%%.*}" -lt 4 ]]; then
     exit 1
 fi
 
-set -uo pipefail
-
 # Debug mode and CI mode
 DEBUG_MODE=0
 CI_MODE=0
@@ -55,99 +53,189 @@ CI_APP=""
 CI_ACTIONS=""
 CLI_CONTAINER_OVERRIDE=0
 CLI_CONTAINER_COMMAND=""
-
-# Parse command line arguments
-while [[ $# -gt 0 ]]; do
-    case $1 in
-        --debug)
-            DEBUG_MODE=1
-            shift
-            ;;
-        --ci)
-            CI_MODE=1
-            shift
-            if [[ $# -gt 0 && ! "$1" =~ ^-- && ! "$1" =~ \.cfg$ ]]; then
-                CI_APP="$1"
+OUTPUT_FORMAT="text"           # CI result format: text, table or json
+CLI_PARALLEL_LIMIT=""          # --parallel N: cap on concurrently running actions
+LIST_MODE=0                    # --list: print apps and actions, then exit
+WORKSPACE_DIR=""               # --workspace DIR: load every *.cfg file in DIR
+HISTORY_LIMIT=""               # --history [N]: print the last N executions, then exit
+HISTORY_FILE="${XDG_DATA_HOME:-$HOME/.local/share}/shell-bun/history.jsonl"
+
+# Function to parse command line arguments
+parse_arguments() {
+    while [[ $# -gt 0 ]]; do
+        case $1 in
+            --debug)
+                DEBUG_MODE=1
+                shift
+                ;;
+            --ci)
+                CI_MODE=1
                 shift
-                if [[ $# -gt 0 && ! "$1" =~ ^-- && ! "$1" =~ \.cfg$ ]]; then
-                    CI_ACTIONS="$1"
+                if [[ $# -gt 0 && ! "$1" =~ ^-- && ! "$1" =~ \.(cfg|toml)$ ]]; then
+                    CI_APP="$1"
                     shift
+                    if [[ $# -gt 0 && ! "$1" =~ ^-- && ! "$1" =~ \.(cfg|toml)$ ]]; then
+                        CI_ACTIONS="$1"
+                        shift
+                    fi
                 fi
-            fi
-            ;;
-        --container)
-            if [[ $# -lt 2 ]]; then
-                echo "Error: --container requires a command argument (use --container <cmd> or --container=<cmd>)"
+                ;;
+            --container)
+                if [[ $# -lt 2 ]]; then
+                    echo "Error: --container requires a command argument (use --container <cmd> or --container=<cmd>)"
+                    exit 1
+                fi
+                CLI_CONTAINER_OVERRIDE=1
+                CLI_CONTAINER_COMMAND="$2"
+                shift 2
+                ;;
+            --container=*)
+                CLI_CONTAINER_OVERRIDE=1
+                CLI_CONTAINER_COMMAND="${1#--container=}"
+                shift
+                ;;
+            --output|--output=*)
+                if [[ "$1" == --output=* ]]; then
+                    OUTPUT_FORMAT="${1#--output=}"
+                    shift
+                elif [[ $# -lt 2 ]]; then
+                    echo "Error: --output requires a format argument (text, table or json)"
+                    exit 1
+                else
+                    OUTPUT_FORMAT="$2"
+                    shift 2
+                fi
+                if [[ "$OUTPUT_FORMAT" != "text" && "$OUTPUT_FORMAT" != "table" && "$OUTPUT_FORMAT" != "json" ]]; then
+                    echo "Error: Unknown output format '$OUTPUT_FORMAT' (expected text, table or json)"
+                    exit 1
+                fi
+                ;;
+            --parallel|--parallel=*)
+                if [[ "$1" == --parallel=* ]]; then
+                    CLI_PARALLEL_LIMIT="${1#--parallel=}"
+                    shift
+                elif [[ $# -lt 2 ]]; then
+                    echo "Error: --parallel requires a number of concurrent actions"
+                    exit 1
+                else
+                    CLI_PARALLEL_LIMIT="$2"
+                    shift 2
+                fi
+                if [[ ! "$CLI_PARALLEL_LIMIT" =~ ^[0-9]+$ ]]; then
+                    echo "Error: Invalid --parallel value '$CLI_PARALLEL_LIMIT' (expected a whole number, 0 for no limit)"
+                    exit 1
+                fi
+                ;;
+            --json)
+                OUTPUT_FORMAT="json"
+                shift
+                ;;
+            --list)
+                LIST_MODE=1
+                shift
+                ;;
+            --workspace|--workspace=*)
+                if [[ "$1" == --workspace=* ]]; then
+                    WORKSPACE_DIR="${1#--workspace=}"
+                    shift
+                elif [[ $# -lt 2 ]]; then
+                    echo "Error: --workspace requires a directory"
+                    exit 1
+                else
+                    WORKSPACE_DIR="$2"
+                    shift 2
+                fi
+                ;;
+            --history)
+                HISTORY_LIMIT=20
+                shift
+                if [[ $# -gt 0 && "$1" =~ ^[0-9]+$ ]]; then
+                    HISTORY_LIMIT="$1"
+                    shift
+                fi
+                ;;
+            --help|-h)
+                echo "Shell-Bun v$VERSION - Interactive build environment script"
+                echo "Copyright (c) 2025, Fredrik Reveny"
+                echo ""
+                echo "Usage:"
+                echo "  $0 [options] [config-file]"
+                echo ""
+                echo "Interactive mode (default):"
+                echo "  $0                         # Use default config (shell-bun.cfg)"
+                echo "  $0 my-config.txt           # Use custom config file"
+                echo "  $0 --debug                 # Enable debug logging"
+                echo "  $0 --container \"podman exec ...\"   # Override container command"
+                echo "  $0 --workspace ./configs/          # Load every *.cfg in a directory (apps become file::App)"
+                echo ""
+                echo "Non-interactive mode (CI/CD) with fuzzy pattern matching:"
+                echo "  $0 --ci APP_PATTERN ACTION_PATTERN   # Run actions matching patterns"
+                echo "  $0 --ci APP ACTION --output table    # Print results as an aligned table"
+                echo "  $0 --ci APP ACTION --json            # Print results as JSON on stdout (other output goes to stderr)"
+                echo "  $0 --ci APP ACTION --parallel 4      # Run at most 4 actions at a time (also in the menu)"
+                echo ""
+                echo "Listing:"
+                echo "  $0 --list [config-file]            # Print one 'app<TAB>action' pair per line"
+                echo "  $0 --list --json [config-file]     # Print the apps and actions as JSON"
+                echo ""
+                echo "History:"
+                echo "  $0 --history [N]                   # Show the last N executions (default 20)"
+                echo ""
+                echo "App pattern examples:"
+                echo "  MyWebApp                    # Exact app name"
+                echo "  *Web*                       # Wildcard: any app containing 'Web'"
+                echo "  API*                        # Wildcard: apps starting with 'API'"
+                echo "  web                         # Substring: apps containing 'web'"
+                echo "  /^api-[0-9]+$/              # Regex: extended regular expression between slashes"
+                echo "  backend                     # Group: every member of [group:backend]"
+                echo "  MyWebApp,API*,mobile        # Multiple: comma-separated patterns"
+                echo ""
+                echo "Action pattern examples:"
+                echo "  build_host                  # Exact action name"
+                echo "  build*                      # Wildcard: actions starting with 'build'"
+                echo "  *host                       # Wildcard: actions ending with 'host'"
+                echo "  test*,deploy                # Multiple specific actions"
+                echo "  unit                        # Substring: actions containing 'unit'"
+                echo "  all                         # All available actions"
+                echo ""
+                echo "Actions are completely user-defined in your config file"
+                echo ""
+                echo "Examples:"
+                echo "  $0 --ci MyWebApp build             # Run build action"
+                echo "  $0 --ci \"*Web*\" test*              # Run test actions on Web apps"
+                echo "  $0 --ci \"API*,Frontend\" all        # Run all actions on API and Frontend"
+                echo "  $0 --ci mobile deploy,test         # Multiple actions for mobile apps"
+                echo "  $0 --ci \"*\" unit_test my.cfg       # Run unit_test on all apps with custom config"
+                exit 0
+                ;;
+            --version|-v)
+                echo "v$VERSION"
+                exit 0
+                ;;
+            -*)
+                echo "Unknown option: $1"
+                echo "Use --help for usage information"
                 exit 1
-            fi
-            CLI_CONTAINER_OVERRIDE=1
-            CLI_CONTAINER_COMMAND="$2"
-            shift 2
-            ;;
-        --container=*)
-            CLI_CONTAINER_OVERRIDE=1
-            CLI_CONTAINER_COMMAND="${1#--container=}"
-            shift
-            ;;
-        --help|-h)
-            echo "Shell-Bun v$VERSION - Interactive build environment script"
-            echo "Copyright (c) 2025, Fredrik Reveny"
-            echo ""
-            echo "Usage:"
-            echo "  $0 [options] [config-file]"
-            echo ""
-            echo "Interactive mode (default):"
-            echo "  $0                         # Use default config (shell-bun.cfg)"
-            echo "  $0 my-config.txt           # Use custom config file"
-            echo "  $0 --debug                 # Enable debug logging"
-            echo "  $0 --container \"podman exec ...\"   # Override container command"
-            echo ""
-            echo "Non-interactive mode (CI/CD) with fuzzy pattern matching:"
-            echo "  $0 --ci APP_PATTERN ACTION_PATTERN   # Run actions matching patterns"
-            echo ""
-            echo "App pattern examples:"
-            echo "  MyWebApp                    # Exact app name"
-            echo "  *Web*                       # Wildcard: any app containing 'Web'"
-            echo "  API*                        # Wildcard: apps starting with 'API'"
-            echo "  web                         # Substring: apps containing 'web'"
-            echo "  MyWebApp,API*,mobile        # Multiple: comma-separated patterns"
-            echo ""
-            echo "Action pattern examples:"
-            echo "  build_host                  # Exact action name"
-            echo "  build*                      # Wildcard: actions starting with 'build'"
-            echo "  *host                       # Wildcard: actions ending with 'host'"
-            echo "  test*,deploy                # Multiple specific actions"
-            echo "  unit                        # Substring: actions containing 'unit'"
-            echo "  all                         # All available actions"
-            echo ""
-            echo "Actions are completely user-defined in your config file"
-            echo ""
-            echo "Examples:"
-            echo "  $0 --ci MyWebApp build             # Run build action"
-            echo "  $0 --ci \"*Web*\" test*              # Run test actions on Web apps"
-            echo "  $0 --ci \"API*,Frontend\" all        # Run all actions on API and Frontend"
-            echo "  $0 --ci mobile deploy,test         # Multiple actions for mobile apps"
-            echo "  $0 --ci \"*\" unit_test my.cfg       # Run unit_test on all apps with custom config"
-            exit 0
-            ;;
-        --version|-v)
-            echo "v$VERSION"
-            exit 0
-            ;;
-        -*)
-            echo "Unknown option: $1"
-            echo "Use --help for usage information"
+                ;;
+            *)
+                CONFIG_FILE="$1"
+                shift
+                ;;
+        esac
+    done
+
+    # A workspace directory takes the place of the single config file
+    if [[ -n "$WORKSPACE_DIR" ]]; then
+        if [[ -n "${CONFIG_FILE:-}" ]]; then
+            echo "Error: --workspace cannot be combined with a config file ('$CONFIG_FILE')"
             exit 1
-            ;;
-        *)
-            CONFIG_FILE="$1"
-            shift
-            ;;
-    esac
-done
+        fi
+        CONFIG_FILE="${WORKSPACE_DIR%/}"
+    fi
 
-# Set default config file if not specified
-CONFIG_FILE="${CONFIG_FILE:-shell-bun.cfg}"
+    # Set default config file if not specified
+    CONFIG_FILE="${CONFIG_FILE:-shell-bun.cfg}"
+}
 
 # Colors for output
 RED='\033[0;31m'
@@ -160,17 +248,98 @@ BOLD='\033[1m'
 DIM='\033[2m'
 NC='\033[0m' # No Color
 
+# Menu color roles, overridable with theme.ROLE keys at the top of the config file
+declare -A DEFAULT_THEME=(
+    [header]="$BLUE"                 # Banner
+    [help]="$CYAN"                   # Key help lines
+    [filter]="$YELLOW"               # Active filter line
+    [selected]="$GREEN"              # Selected items and the selection count
+    [highlight]="$CYAN"              # Highlighted item
+    [details]="$YELLOW"              # "Show Details" entries
+    [details_highlight]="$BOLD$PURPLE" # Highlighted "Show Details" entry
+    [dim]="$DIM"                     # Placeholders and scroll indicators
+    [error]="$RED"                   # "No matches found"
+    [group]="$BOLD$BLUE"             # Group headers
+)
+declare -A THEME=()
+
+# Function to restore the default menu colors
+reset_theme() {
+    THEME=()
+    local role
+    for role in "${!DEFAULT_THEME[@]}"; do
+        THEME["$role"]="${DEFAULT_THEME[$role]}"
+    done
+}
+reset_theme
+
+# Function to turn a theme color spec into an escape sequence
+# Accepts space-separated words: color names (red, bright_blue, gray...), 0-255, #RRGGBB, bold, dim, underline, default
+theme_color_escape() {
+    local spec="$1"
+    local -A color_codes=(
+        [black]=30 [red]=31 [green]=32 [yellow]=33 [blue]=34 [magenta]=35 [purple]=35 [cyan]=36 [white]=37
+        [gray]=90 [grey]=90
+    )
+    local escape="" word
+    for word in ${spec,,}; do
+        if [[ -n "${color_codes[$word]+set}" ]]; then
+            escape+="\\033[${color_codes[$word]}m"
+        elif [[ "$word" =~ ^bright_(.+)$ && -n "${color_codes[${BASH_REMATCH[1]}]+set}" ]]; then
+            escape+="\\033[$(( ${color_codes[${BASH_REMATCH[1]}]} % 60 + 60 ))m"
+        elif [[ "$word" =~ ^[0-9]{1,3}$ && $((10#$word)) -le 255 ]]; then
+            escape+="\\033[38;5;$((10#$word))m"
+        elif [[ "$word" =~ ^#([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$ ]]; then
+            escape+="\\033[38;2;$((16#${BASH_REMATCH[1]}));$((16#${BASH_REMATCH[2]}));$((16#${BASH_REMATCH[3]}))m"
+        elif [[ "$word" == "bold" ]]; then
+            escape+="\\033[1m"
+        elif [[ "$word" == "dim" ]]; then
+            escape+="\\033[2m"
+        elif [[ "$word" == "underline" ]]; then
+            escape+="\\033[4m"
+        elif [[ "$word" == "default" ]]; then
+            escape+="\\033[0m"
+        else
+            return 1
+        fi
+    done
+    [[ -n "$escape" ]] || return 1
+    echo "$escape"
+}
+
 # Global variables
 declare -a APPS=()
 declare -A APP_ACTIONS=()      # Key: "app:action", Value: "command"
 declare -A APP_ACTION_LIST=()  # Key: "app", Value: "space-separated list of actions"
 declare -A APP_WORKING_DIR=()
 declare -A APP_LOG_DIR=()      # Key: "app", Value: "log directory path"
+declare -A APP_CONTAINER=()    # Key: "app", Value: "container command" (empty = run on host)
+declare -A APP_TIMEOUT=()      # Key: "app", Value: "timeout in seconds" (0 = no timeout)
+declare -A APP_SHELL=()        # Key: "app", Value: "shell that runs the app's commands"
+declare -A APP_ENV=()          # Key: "app:NAME", Value: "environment variable value"
+declare -A APP_ENV_NAMES=()    # Key: "app", Value: "space-separated variable names in config order"
+declare -A APP_ACTION_DEPS=()  # Key: "app:action", Value: "space-separated actions to run first"
+declare -a GROUP_NAMES=()      # [group:NAME] sections in config order
+declare -A GROUP_MEMBERS=()    # Key: "group", Value: "comma-separated member apps"
+declare -A APP_GROUP=()        # Key: "app", Value: "group it belongs to"
 declare -a SELECTED_ITEMS=()
+SESSION_FILE=".shell-bun-session" # Selection kept between interactive runs (in the current directory)
 declare -a EXECUTION_RESULTS=() # Track execution results for log viewing
 GLOBAL_LOG_DIR=""              # Global log directory from config
+GLOBAL_LOG_NAME_TEMPLATE=""    # Global log_name_template from config (empty = {timestamp}_{app}_{action}.log)
+GLOBAL_TIMEOUT=""              # Global action timeout in seconds from config (empty = no timeout)
+GLOBAL_SHELL=""                # Global shell from config (empty = bash)
+CONFIG_PARALLEL_LIMIT=""       # Global parallel= cap from config (empty or 0 = no limit)
+TIMEOUT_EXIT_CODE=124          # Exit code reported for timed out actions (same as GNU timeout)
+CI_EXIT_NO_MATCH=2             # --ci: no application or action matched the patterns
+CI_EXIT_PARTIAL_FAILURE=3      # --ci: some actions failed, the others succeeded
+CI_EXIT_ALL_FAILED=4           # --ci: every action failed
+CONFIG_DIR=""                  # Directory containing the config file
+PATH_RELATIVE_TO_CONFIG=0      # Resolve relative working_dir against CONFIG_DIR instead of the script dir
 CONFIG_CONTAINER_COMMAND=""    # Container command defined in config (if any)
 CONTAINER_COMMAND=""           # Effective container command after CLI overrides
+DEFAULT_KNOWN_RUNTIMES="docker podman nerdctl lima"
+KNOWN_RUNTIMES="$DEFAULT_KNOWN_RUNTIMES"  # Container runtimes recognised without a warning
 CONTAINER_ENV_FILE="${SHELL_BUN_CONTAINER_MARKER_FILE:-/run/.containerenv}"
 
 # Helper functions for safely working with SELECTED_ITEMS under set -u and
@@ -202,6 +371,29 @@ print_color() {
     echo -e "${color}${message}${NC}"
 }
 
+# Function to get the current time in milliseconds
+current_time_ms() {
+    if [[ -n "${EPOCHREALTIME:-}" ]]; then
+        # EPOCHREALTIME (bash 5+) has microsecond precision
+        local now="${EPOCHREALTIME/[.,]/}"
+        echo $((10#$now / 1000))
+    else
+        echo $(($(date +%s) * 1000))
+    fi
+}
+
+# Function to format a duration in milliseconds for display
+format_duration() {
+    local ms="$1"
+    if [[ $ms -lt 1000 ]]; then
+        echo "${ms}ms"
+    elif [[ $ms -lt 60000 ]]; then
+        printf '%d.%ds\n' $((ms / 1000)) $((ms % 1000 / 100))
+    else
+        echo "$((ms / 60000))m $((ms % 60000 / 1000))s"
+    fi
+}
+
 # Debug logging function
 debug_log() {
     if [[ $DEBUG_MODE -eq 1 ]]; then
@@ -209,11 +401,48 @@ debug_log() {
     fi
 }
 
-# Function to generate log file path
-generate_log_file_path() {
+# Function to check whether a config value means "enabled"
+config_value_is_true() {
+    case "${1,,}" in
+        true|yes|on|1) return 0 ;;
+        *) return 1 ;;
+    esac
+}
+
+# Function to get the directory relative working directories are resolved against
+resolve_base_dir() {
+    if [[ $PATH_RELATIVE_TO_CONFIG -eq 1 && -n "$CONFIG_DIR" ]]; then
+        echo "$CONFIG_DIR"
+    else
+        cd "$(dirname "${BASH_SOURCE[0]}")" && pwd
+    fi
+}
+
+# Function to resolve the host working directory for an app
+resolve_working_dir() {
+    local app="$1"
+    local base_dir
+    base_dir=$(resolve_base_dir)
+    local working_dir="${APP_WORKING_DIR[$app]:-}"
+    
+    if [[ -z "$working_dir" ]]; then
+        working_dir="$base_dir"
+    fi
+    
+    # Expand tilde in working_dir if present
+    working_dir="${working_dir/#\~/$HOME}"
+    
+    # Make relative paths relative to the base directory
+    if [[ ! "$working_dir" =~ ^/ ]]; then
+        working_dir="$base_dir/$working_dir"
+    fi
+    
+    echo "$working_dir"
+}
+
+# Function to resolve the absolute log directory for an app (without creating it)
+resolve_log_dir() {
     local app="$1"
-    local action="$2"
-    local timestamp=$(date '+%Y%m%d_%H%M%S')
     local script_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
     
     # Get log directory - check app-specific first, then global, then default
@@ -232,17 +461,87 @@ generate_log_file_path() {
         log_dir="$script_dir/$log_dir"
     fi
     
+    echo "$log_dir"
+}
+
+# Function to generate log file path
+generate_log_file_path() {
+    local app="$1"
+    local action="$2"
+    local timestamp=$(date '+%Y%m%d_%H%M%S')
+    local script_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+    local log_dir
+    log_dir=$(resolve_log_dir "$app")
+    
     # Create log directory if it doesn't exist
     mkdir -p "$log_dir" 2>/dev/null || {
         echo "Warning: Cannot create log directory '$log_dir', using script directory"
         log_dir="$script_dir"
     }
     
-    # Generate log file name: timestamp_app_action.log
-    local log_file="$log_dir/${timestamp}_${app}_${action}.log"
+    # Generate log file name from log_name_template (default: timestamp_app_action.log)
+    local log_name="$GLOBAL_LOG_NAME_TEMPLATE"
+    [[ -z "$log_name" ]] && log_name='{timestamp}_{app}_{action}.log'
+    log_name="${log_name//\{timestamp\}/$timestamp}"
+    log_name="${log_name//\{date\}/$(date '+%Y-%m-%d')}"
+    log_name="${log_name//\{app\}/$app}"
+    log_name="${log_name//\{action\}/$action}"
+    local log_file="$log_dir/$log_name"
     echo "$log_file"
 }
 
+# Function to list the actions configured for an app, one per line in config order
+get_actions() {
+    local app="$1"
+    local actions="${APP_ACTION_LIST[$app]:-}"
+    if [[ -n "$actions" ]]; then
+        printf '%s\n' $actions
+    fi
+}
+
+# Function to get the member apps of a group, one per line
+get_group_members() {
+    local group="$1"
+    local -a members=()
+    IFS=',' read -ra members <<< "${GROUP_MEMBERS[$group]:-}"
+    if [[ ${#members[@]} -gt 0 ]]; then
+        printf '%s\n' "${members[@]}"
+    fi
+}
+
+# Function to count configured apps
+app_count() {
+    printf '%d\n' "${#APPS[@]}"
+}
+
+# Function to count the actions configured for one app
+action_count_for_app() {
+    local app="$1"
+    local -a actions=()
+    readarray -t actions < <(get_actions "$app")
+    printf '%d\n' "${#actions[@]}"
+}
+
+# Function to count all configured actions across apps
+action_count() {
+    local total=0
+    local app
+    for app in "${APPS[@]}"; do
+        total=$((total + $(action_count_for_app "$app")))
+    done
+    printf '%d\n' "$total"
+}
+
+# Function to list every app/action pair in config order, one tab-separated pair per line
+all_action_pairs() {
+    local app action
+    for app in "${APPS[@]}"; do
+        while IFS= read -r action; do
+            printf '%s\t%s\n' "$app" "$action"
+        done < <(get_actions "$app")
+    done
+}
+
 # Function to log execution status
 log_execution() {
     local app="$1"
@@ -267,30 +566,347 @@ log_execution() {
     esac
 }
 
-# Function to parse configuration file
-parse_config() {
-    if [[ ! -f "$CONFIG_FILE" ]]; then
-        print_color "$RED" "Error: Configuration file '$CONFIG_FILE' not found!"
+# Function to find the byte offset of the first NUL byte in a file (prints nothing if none)
+find_nul_byte_offset() {
+    local file="$1"
+    local line_number
+    line_number=$(od -An -v -tx1 "$file" | tr -s ' ' '\n' | sed '/^$/d' | grep -n -m1 '^00$' | cut -d: -f1)
+    if [[ -n "$line_number" ]]; then
+        echo $((line_number - 1))
+    fi
+}
+
+# Function to find the byte offset of the first invalid UTF-8 sequence (prints nothing if valid)
+find_invalid_utf8_offset() {
+    local file="$1"
+    command -v iconv >/dev/null 2>&1 || return 0
+    
+    local iconv_error
+    if iconv_error=$(iconv -f UTF-8 -t UTF-8 "$file" 2>&1 >/dev/null); then
+        return 0
+    fi
+    if [[ "$iconv_error" =~ position\ ([0-9]+) ]]; then
+        echo "${BASH_REMATCH[1]}"
+    else
+        echo "unknown"
+    fi
+}
+
+# Function to check that the configuration file can be parsed, exiting with a specific error if not
+check_config_file() {
+    local file="$1"
+    
+    if [[ -d "$file" ]]; then
+        print_color "$RED" "Error: Configuration file '$file' is a directory!"
+        echo "Usage: $0 [config-file]"
+        exit 1
+    fi
+    
+    if [[ ! -f "$file" ]]; then
+        print_color "$RED" "Error: Configuration file '$file' not found!"
         echo "Please create a configuration file or specify a different one."
         echo "Usage: $0 [config-file]"
         exit 1
     fi
+    
+    if [[ ! -r "$file" ]]; then
+        print_color "$RED" "Error: Cannot read configuration file '$file' (permission denied)"
+        exit 1
+    fi
+    
+    if [[ ! -s "$file" ]]; then
+        print_color "$RED" "Error: Configuration file '$file' is empty!"
+        exit 1
+    fi
+    
+    # NUL bytes mean a binary or corrupted file; bash would silently drop them
+    local nul_offset
+    nul_offset=$(find_nul_byte_offset "$file")
+    if [[ -n "$nul_offset" ]]; then
+        print_color "$RED" "Error: Configuration file '$file' appears to be corrupted (NUL byte at byte offset $nul_offset)"
+        exit 1
+    fi
+    
+    # Non-UTF-8 text still parses, but usually means the file was saved with the wrong encoding
+    local utf8_offset
+    utf8_offset=$(find_invalid_utf8_offset "$file")
+    if [[ -n "$utf8_offset" ]]; then
+        print_color "$YELLOW" "Warning: Configuration file '$file' contains non-UTF-8 characters at byte offset $utf8_offset"
+    fi
+}
 
-    local current_app=""
+# Function to print an action's dependencies (ACTION.depends) in run order, each only once
+# Returns 1 and prints the loop (a -> b -> a) instead when the dependencies form a cycle
+resolve_action_dependencies() {
+    local app="$1"
+    local action="$2"
+    local -A dependency_state=()    # Key: action, Value: visiting or done
+    local -a dependency_order=()
+    local -a dependency_path=()
+    
+    visit_action_dependency "$app" "$action" || return 1
+    # The action itself comes last; everything before it is a prerequisite
+    if [[ ${#dependency_order[@]} -gt 1 ]]; then
+        printf '%s\n' "${dependency_order[@]:0:${#dependency_order[@]}-1}"
+    fi
+}
+
+# Depth-first step of resolve_action_dependencies (uses its local state)
+visit_action_dependency() {
+    local app="$1"
+    local action="$2"
+    
+    if [[ "${dependency_state[$action]:-}" == "done" ]]; then
+        return 0
+    fi
+    dependency_path+=("$action")
+    if [[ "${dependency_state[$action]:-}" == "visiting" ]]; then
+        local step cycle="" in_cycle=false
+        for step in "${dependency_path[@]}"; do
+            [[ "$step" == "$action" ]] && in_cycle=true
+            [[ "$in_cycle" == "true" ]] && cycle="${cycle:+$cycle -> }$step"
+        done
+        echo "$cycle"
+        return 1
+    fi
+    
+    dependency_state["$action"]="visiting"
+    local dependency
+    for dependency in ${APP_ACTION_DEPS[$app:$action]:-}; do
+        visit_action_dependency "$app" "$dependency" || return 1
+    done
+    dependency_state["$action"]="done"
+    dependency_path=("${dependency_path[@]:0:${#dependency_path[@]}-1}")
+    dependency_order+=("$action")
+}
+
+# Function to parse configuration file
+# Function to decode a TOML value into plain text
+# Supports basic strings ("..." with escapes), literal strings ('...') and bare
+# scalars such as true or 42, each optionally followed by a # comment.
+# Returns 1 for anything else (arrays, inline tables, multi-line strings).
+toml_decode_value() {
+    local raw="$1"
+    
+    if [[ "$raw" =~ ^\"((\\.|[^\"\\])*)\"[[:space:]]*(#.*)?$ ]]; then
+        local inner="${BASH_REMATCH[1]}"
+        local decoded=""
+        local i ch
+        for ((i = 0; i < ${#inner}; i++)); do
+            ch="${inner:i:1}"
+            if [[ "$ch" != "\\" ]]; then
+                decoded+="$ch"
+                continue
+            fi
+            ((i++))
+            ch="${inner:i:1}"
+            case "$ch" in
+                n) decoded+=$'\n' ;;
+                t) decoded+=$'\t' ;;
+                r) decoded+=$'\r' ;;
+                '"'|"\\") decoded+="$ch" ;;
+                u)
+                    # \uXXXX: encode the code point as UTF-8 independently of the current locale
+                    [[ "${inner:i+1:4}" =~ ^[0-9A-Fa-f]{4}$ ]] || return 1
+                    local code_point=$((16#${inner:i+1:4}))
+                    local utf8_escapes
+                    if [[ $code_point -lt 128 ]]; then
+                        printf -v utf8_escapes '\\x%02x' "$code_point"
+                    elif [[ $code_point -lt 2048 ]]; then
+                        printf -v utf8_escapes '\\x%02x\\x%02x' $((0xC0 | code_point >> 6)) $((0x80 | (code_point & 0x3F)))
+                    else
+                        printf -v utf8_escapes '\\x%02x\\x%02x\\x%02x' $((0xE0 | code_point >> 12)) $((0x80 | ((code_point >> 6) & 0x3F))) $((0x80 | (code_point & 0x3F)))
+                    fi
+                    decoded+=$(printf '%b' "$utf8_escapes")
+                    ((i += 4))
+                    ;;
+                *) return 1 ;;
+            esac
+        done
+        printf '%s' "$decoded"
+    elif [[ "$raw" =~ ^\'([^\']*)\'[[:space:]]*(#.*)?$ ]]; then
+        printf '%s' "${BASH_REMATCH[1]}"
+    elif [[ "$raw" =~ ^([A-Za-z0-9_.:+-]+)[[:space:]]*(#.*)?$ ]]; then
+        printf '%s' "${BASH_REMATCH[1]}"
+    else
+        return 1
+    fi
+}
+
+# Function to strip the quotes from a TOML key or table name ("My App" -> My App)
+toml_unquote_name() {
+    local name="$1"
+    
+    if [[ "$name" =~ ^\"(.*)\"$ || "$name" =~ ^\'(.*)\'$ ]]; then
+        name="${BASH_REMATCH[1]}"
+    fi
+    printf '%s' "$name"
+}
+
+# Function to clear everything parse_config fills in, so the config can be parsed again
+reset_config_state() {
+    APPS=()
+    APP_ACTIONS=()
+    APP_ACTION_LIST=()
+    APP_WORKING_DIR=()
+    APP_LOG_DIR=()
+    APP_CONTAINER=()
+    APP_TIMEOUT=()
+    APP_SHELL=()
+    APP_ENV=()
+    APP_ENV_NAMES=()
+    APP_ACTION_DEPS=()
+    GROUP_NAMES=()
+    GROUP_MEMBERS=()
+    APP_GROUP=()
+    GLOBAL_LOG_DIR=""
+    GLOBAL_LOG_NAME_TEMPLATE=""
+    GLOBAL_TIMEOUT=""
+    GLOBAL_SHELL=""
+    CONFIG_PARALLEL_LIMIT=""
+    PATH_RELATIVE_TO_CONFIG=0
     CONFIG_CONTAINER_COMMAND=""
+    CONTAINER_COMMAND=""
+    KNOWN_RUNTIMES="$DEFAULT_KNOWN_RUNTIMES"
+    reset_theme
+}
+
+# Function to print a signature (modification time and size) that changes when the config file is edited
+# For a workspace directory, the signature covers the directory and every *.cfg file in it
+config_file_signature() {
+    local file="$1"
+    if [[ -d "$file" ]]; then
+        local workspace_file signature=""
+        for workspace_file in "$file" "$file"/*.cfg; do
+            [[ -e "$workspace_file" ]] && signature="$signature$(config_file_signature "$workspace_file");"
+        done
+        echo "$signature"
+        return 0
+    fi
+    stat -c '%Y %s' "$file" 2>/dev/null || stat -f '%m %z' "$file" 2>/dev/null
+}
+
+# Function to re-read the config file in place (used by the interactive menu)
+# A config that fails to parse leaves the current one untouched; the error is stored in RELOAD_ERROR
+RELOAD_ERROR=""
+reload_config() {
+    local check_output
+    if ! check_output=$( (reset_config_state; parse_config) 2>&1 ); then
+        # Keep the first error line (plus the detail line when it ends in a colon) without colors
+        RELOAD_ERROR=$(echo "$check_output" | sed $'s/\x1b\\[[0-9;]*m//g' | grep -A1 -m1 '^Error: ')
+        RELOAD_ERROR="${RELOAD_ERROR#Error: }"
+        if [[ "$RELOAD_ERROR" == *:$'\n'* ]]; then
+            RELOAD_ERROR="${RELOAD_ERROR%%$'\n'*} $(echo "${RELOAD_ERROR#*$'\n'}" | sed 's/^[[:space:]]*//')"
+        else
+            RELOAD_ERROR="${RELOAD_ERROR%%$'\n'*}"
+        fi
+        return 1
+    fi
+    
+    RELOAD_ERROR=""
+    reset_config_state
+    parse_config > /dev/null 2>&1
+    
+    # Drop selections whose app or action no longer exists
+    local -a kept_items=()
+    local item
+    if selected_items_defined; then
+        for item in "${SELECTED_ITEMS[@]}"; do
+            if [[ -n "${APP_ACTIONS[${item%% - *}:${item#* - }]+set}" ]]; then
+                kept_items+=("$item")
+            fi
+        done
+    fi
+    SELECTED_ITEMS=(${kept_items[@]+"${kept_items[@]}"})
+    return 0
+}
+
+# Read one configuration file into the global tables; included files are
+# loaded depth-first after it, relative to the directory of the including file
+parse_config_file() {
+    local config_source="$1"
+
+    # Files ending in .toml use TOML syntax for the same sections and keys
+    local config_format="ini"
+    if [[ "$config_source" == *.toml ]]; then
+        config_format="toml"
+    fi
+
+    local current_app=""
+    local current_group=""         # Set inside a [group:NAME] section
+    local line_number=0
+    local -a include_files=()
+    local canonical_source
+    canonical_source="$(cd "$(dirname "$config_source")" && pwd)/$(basename "$config_source")"
+    include_stack+=("$canonical_source")
+    loaded_config_files["$canonical_source"]=1
     
     while IFS= read -r line || [[ -n "$line" ]]; do
+        ((line_number++))
         # Skip empty lines and comments
         [[ -z "$line" || "$line" =~ ^[[:space:]]*# ]] && continue
         
         # Remove leading/trailing whitespace
         line=$(echo "$line" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
         
-        if [[ "$line" =~ ^\[(.+)\]$ ]]; then
+        if [[ "$config_format" == "toml" && "$line" =~ ^\[\[ ]]; then
+            print_color "$RED" "Error: Unsupported TOML table on line $line_number of '$config_source' (arrays of tables are not supported)"
+            exit 1
+        elif [[ "$config_format" == "toml" && "$line" =~ ^\[([^]]+)\][[:space:]]*(#.*)?$ ]]; then
+            # TOML table: strip an optional trailing comment and quotes around the name
+            line="[$(toml_unquote_name "$(echo "${BASH_REMATCH[1]}" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')")]"
+        fi
+        
+        if [[ "$line" =~ ^\[group:(.+)\]$ ]]; then
+            # Group section: collects apps under one collapsible menu header
+            current_group="$app_namespace$(echo "${BASH_REMATCH[1]}" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')"
+            current_app=""
+            if [[ -z "${GROUP_MEMBERS[$current_group]+set}" ]]; then
+                GROUP_NAMES+=("$current_group")
+                GROUP_MEMBERS["$current_group"]=""
+            fi
+        elif [[ "$line" =~ ^\[(.+)\]$ ]]; then
             # New application section
-            current_app="${BASH_REMATCH[1]}"
-            APPS+=("$current_app")
-            APP_ACTION_LIST["$current_app"]=""
+            current_app="$app_namespace${BASH_REMATCH[1]}"
+            if [[ -n "$WORKSPACE_DIR" && -n "${app_sources[$current_app]+set}" && "${app_sources[$current_app]}" != "$config_source" ]]; then
+                print_color "$YELLOW" "Warning: App '$current_app' is defined in both '${app_sources[$current_app]}' and '$config_source'; their actions are merged"
+            fi
+            app_sources["$current_app"]="$config_source"
+            current_group=""
+            # A section seen before (e.g. in an included file) adds to the same app
+            if [[ -z "${APP_ACTION_LIST[$current_app]+set}" ]]; then
+                APPS+=("$current_app")
+                APP_ACTION_LIST["$current_app"]=""
+            fi
+        elif [[ -n "$current_group" && "$line" =~ ^([^=]+)=(.*)$ ]]; then
+            # Group directive: only members=app1,app2 is supported
+            local group_key
+            group_key=$(echo "${BASH_REMATCH[1]}" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+            local group_value="${BASH_REMATCH[2]}"
+            if [[ "$config_format" == "toml" ]]; then
+                group_key=$(toml_unquote_name "$group_key")
+                if ! group_value=$(toml_decode_value "$(echo "$group_value" | sed 's/^[[:space:]]*//')"); then
+                    print_color "$RED" "Error: Unsupported TOML value for '$group_key' on line $line_number of '$config_source' (use a string, boolean or number)"
+                    exit 1
+                fi
+            fi
+            if [[ "$group_key" != "members" ]]; then
+                print_color "$RED" "Error: Unknown key '$group_key' in [group:$current_group] on line $line_number of '$config_source' (only 'members' is supported)"
+                exit 1
+            fi
+            local member
+            IFS=',' read -ra group_member_names <<< "$group_value"
+            for member in "${group_member_names[@]}"; do
+                member=$(echo "$member" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                [[ -z "$member" ]] && continue
+                member="$app_namespace$member"
+                if [[ -n "${APP_GROUP[$member]+set}" ]]; then
+                    print_color "$RED" "Error: App '$member' on line $line_number of '$config_source' is already a member of group '${APP_GROUP[$member]}'"
+                    exit 1
+                fi
+                APP_GROUP["$member"]="$current_group"
+                GROUP_MEMBERS["$current_group"]="${GROUP_MEMBERS[$current_group]:+${GROUP_MEMBERS[$current_group]},}$member"
+            done
         elif [[ "$line" =~ ^([^=]+)=(.*)$ ]]; then
             # Configuration directive
             local key="${BASH_REMATCH[1]}"
@@ -299,32 +915,267 @@ parse_config() {
             # Strip whitespace from key
             key=$(echo "$key" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
             
-            if [[ -z "$current_app" && "$key" == "log_dir" ]]; then
+            if [[ "$config_format" == "toml" ]]; then
+                key=$(toml_unquote_name "$key")
+                if ! value=$(toml_decode_value "$(echo "$value" | sed 's/^[[:space:]]*//')"); then
+                    print_color "$RED" "Error: Unsupported TOML value for '$key' on line $line_number of '$config_source' (use a string, boolean or number)"
+                    exit 1
+                fi
+            fi
+            
+            # Globals from an included file never override ones set by an earlier file
+            if [[ -z "$current_app" && "$key" != "include" && "$key" != "known_runtimes" ]]; then
+                if [[ -n "${global_key_sources[$key]+set}" && "${global_key_sources[$key]}" != "$config_source" ]]; then
+                    continue
+                fi
+                global_key_sources["$key"]="$config_source"
+            fi
+            
+            if [[ -z "$current_app" && "$key" == "include" ]]; then
+                # Another config file, loaded once this file has been read
+                value=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                if [[ -z "$value" ]]; then
+                    print_color "$RED" "Error: Empty include path on line $line_number of '$config_source'"
+                    exit 1
+                fi
+                [[ "$value" != /* ]] && value="$(dirname "$config_source")/$value"
+                include_files+=("$value")
+            elif [[ "$key" == "timeout" ]]; then
+                # Action timeout in seconds (global, or per-app override)
+                value=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                if [[ ! "$value" =~ ^[0-9]+$ ]]; then
+                    print_color "$RED" "Error: Invalid timeout '$value' on line $line_number of '$config_source' (expected whole seconds, 0 for no timeout)"
+                    exit 1
+                fi
+                if [[ -n "$current_app" ]]; then
+                    APP_TIMEOUT["$current_app"]="$value"
+                else
+                    GLOBAL_TIMEOUT="$value"
+                fi
+            elif [[ "$key" == "shell" ]]; then
+                # Shell that runs the commands (global, or per-app override)
+                value=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                if [[ -z "$value" || "$value" =~ [[:space:]] ]]; then
+                    print_color "$RED" "Error: Invalid shell '$value' on line $line_number of '$config_source' (expected a program name or path such as zsh or /bin/sh)"
+                    exit 1
+                fi
+                if [[ -n "$current_app" ]]; then
+                    APP_SHELL["$current_app"]="$value"
+                else
+                    GLOBAL_SHELL="$value"
+                fi
+            elif [[ -z "$current_app" && "$key" == "parallel" ]]; then
+                # Cap on concurrently running actions (--parallel overrides it)
+                value=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                if [[ ! "$value" =~ ^[0-9]+$ ]]; then
+                    print_color "$RED" "Error: Invalid parallel value '$value' on line $line_number of '$config_source' (expected a whole number, 0 for no limit)"
+                    exit 1
+                fi
+                CONFIG_PARALLEL_LIMIT="$value"
+            elif [[ -z "$current_app" && "$key" == "log_dir" ]]; then
                 # Global log_dir setting (outside any app section)
                 GLOBAL_LOG_DIR="$value"
+            elif [[ -z "$current_app" && "$key" == "log_name_template" ]]; then
+                # Log file name with {app}, {action}, {timestamp} and {date} placeholders
+                value=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                local unknown_placeholder
+                unknown_placeholder=$(printf '%s' "$value" | grep -o '{[^}]*}' | grep -v -x -e '{app}' -e '{action}' -e '{timestamp}' -e '{date}' | head -n 1)
+                if [[ -z "$value" || "$value" == */* || -n "$unknown_placeholder" ]]; then
+                    print_color "$RED" "Error: Invalid log_name_template '$value' on line $line_number of '$config_source' (use a file name with {app}, {action}, {timestamp} and {date} placeholders)"
+                    exit 1
+                fi
+                GLOBAL_LOG_NAME_TEMPLATE="$value"
             elif [[ -z "$current_app" && "$key" == "container" ]]; then
                 # Global container command (outside any app section)
                 CONFIG_CONTAINER_COMMAND="$value"
+            elif [[ -z "$current_app" && "$key" == "known_runtimes" ]]; then
+                # Additional container runtimes (comma-separated) accepted without a warning
+                KNOWN_RUNTIMES="$KNOWN_RUNTIMES ${value//,/ }"
+            elif [[ "$key" == theme.* ]]; then
+                # Menu color for a UI role: theme.ROLE=color (global only)
+                local theme_role="${key#theme.}"
+                local theme_escape=""
+                if [[ -n "$current_app" ]]; then
+                    print_color "$RED" "Error: '$key' on line $line_number of '$config_source' must be set before the first [section]"
+                    exit 1
+                fi
+                if [[ -z "${DEFAULT_THEME[$theme_role]+set}" ]]; then
+                    print_color "$RED" "Error: Unknown theme role '$theme_role' on line $line_number of '$config_source' (known roles: $(printf '%s\n' "${!DEFAULT_THEME[@]}" | sort | tr '\n' ' ' | sed 's/ $//'))"
+                    exit 1
+                fi
+                if ! theme_escape=$(theme_color_escape "$value"); then
+                    print_color "$RED" "Error: Invalid theme color '$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')' for '$key' on line $line_number of '$config_source' (use a color name, 0-255 or #RRGGBB)"
+                    exit 1
+                fi
+                THEME["$theme_role"]="$theme_escape"
+            elif [[ -z "$current_app" && "$key" == "path_relative_to_config" ]]; then
+                # Opt-in: resolve relative working_dir values against the config file's directory
+                if config_value_is_true "$value"; then
+                    PATH_RELATIVE_TO_CONFIG=1
+                else
+                    PATH_RELATIVE_TO_CONFIG=0
+                fi
             elif [[ -n "$current_app" && "$key" == "working_dir" ]]; then
                 # Special handling for working_dir
                 APP_WORKING_DIR["$current_app"]="$value"
             elif [[ -n "$current_app" && "$key" == "log_dir" ]]; then
                 # Special handling for log_dir (per-app override)
                 APP_LOG_DIR["$current_app"]="$value"
+            elif [[ -n "$current_app" && "$key" == env.* ]]; then
+                # Environment variable for this app's commands: env.NAME=value
+                local env_name="${key#env.}"
+                if [[ ! "$env_name" =~ ^[A-Za-z_][A-Za-z0-9_]*$ ]]; then
+                    print_color "$RED" "Error: Invalid environment variable name '$env_name' on line $line_number of '$config_source'"
+                    exit 1
+                fi
+                if [[ -z "${APP_ENV[$current_app:$env_name]+set}" ]]; then
+                    APP_ENV_NAMES["$current_app"]="${APP_ENV_NAMES[$current_app]:+${APP_ENV_NAMES[$current_app]} }$env_name"
+                fi
+                APP_ENV["$current_app:$env_name"]=$(echo "$value" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+            elif [[ -n "$current_app" && "$key" == *.depends ]]; then
+                # Prerequisite actions of the same app: ACTION.depends=clean,setup
+                local dependent_action="${key%.depends}"
+                local dependency dependency_list=""
+                IFS=',' read -ra dependency_names <<< "$value"
+                for dependency in "${dependency_names[@]}"; do
+                    dependency=$(echo "$dependency" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+                    [[ -n "$dependency" ]] && dependency_list="${dependency_list:+$dependency_list }$dependency"
+                done
+                APP_ACTION_DEPS["$current_app:$dependent_action"]="$dependency_list"
+                dependency_lines["$current_app:$dependent_action"]="line $line_number of '$config_source'"
+            elif [[ -n "$current_app" && "$key" == "container" ]]; then
+                # Per-app container command (takes precedence over --container and the global value)
+                APP_CONTAINER["$current_app"]="$value"
             elif [[ -n "$current_app" ]]; then
-                # Generic action - store the command and add to action list
+                # Generic action - a repeated name is collected and reported after parsing
+                if [[ -n "${APP_ACTIONS[$current_app:$key]+set}" ]]; then
+                    if [[ " ${duplicate_actions[*]} " != *" $current_app:$key "* ]]; then
+                        duplicate_actions+=("$current_app:$key")
+                    fi
+                    continue
+                fi
+
+                # Store the command and add to action list
                 APP_ACTIONS["$current_app:$key"]="$value"
-                
-                # Add to action list if not already present
                 local current_actions="${APP_ACTION_LIST[$current_app]}"
                 if [[ -z "$current_actions" ]]; then
                     APP_ACTION_LIST["$current_app"]="$key"
-                elif [[ "$current_actions" != *"$key"* ]]; then
+                else
                     APP_ACTION_LIST["$current_app"]="$current_actions $key"
                 fi
             fi
         fi
-    done < "$CONFIG_FILE"
+    done < "$config_source"
+
+    local include_file canonical_include
+    for include_file in "${include_files[@]}"; do
+        if [[ ! -d "$(dirname "$include_file")" ]]; then
+            check_config_file "$include_file"
+        fi
+        canonical_include="$(cd "$(dirname "$include_file")" && pwd)/$(basename "$include_file")"
+        local stacked
+        for stacked in "${include_stack[@]}"; do
+            if [[ "$stacked" == "$canonical_include" ]]; then
+                local include_chain="" chain_file
+                for chain_file in "${include_stack[@]}" "$canonical_include"; do
+                    include_chain="${include_chain:+$include_chain -> }$chain_file"
+                done
+                print_color "$RED" "Error: Circular include in '$config_source': $include_chain"
+                exit 1
+            fi
+        done
+        [[ -n "${loaded_config_files[$canonical_include]+set}" ]] && continue
+        check_config_file "$include_file"
+        parse_config_file "$include_file"
+    done
+
+    unset 'include_stack[-1]'
+}
+
+parse_config() {
+    local -a duplicate_actions=()
+    local -A dependency_lines=()    # Key: "app:action", Value: "line N of 'file'" of its .depends key
+    local -a include_stack=()       # Files currently being read, outermost first
+    local -A loaded_config_files=() # Every file read so far, by absolute path
+    local -A global_key_sources=()  # Global key -> file that set it
+    local -A app_sources=()         # App -> file whose section defined it
+    local app_namespace=""          # "file::" prefix of app and group names in workspace mode
+    CONFIG_CONTAINER_COMMAND=""
+    
+    if [[ -n "$WORKSPACE_DIR" ]]; then
+        # Workspace: every *.cfg file in the directory, in name order, apps qualified as file::App
+        if [[ ! -d "$WORKSPACE_DIR" ]]; then
+            print_color "$RED" "Error: Workspace directory '$WORKSPACE_DIR' not found!"
+            exit 1
+        fi
+        CONFIG_DIR="$(cd "$WORKSPACE_DIR" && pwd)"
+        local -a workspace_files=()
+        local workspace_file
+        for workspace_file in "$CONFIG_DIR"/*.cfg; do
+            [[ -f "$workspace_file" ]] && workspace_files+=("$workspace_file")
+        done
+        if [[ ${#workspace_files[@]} -eq 0 ]]; then
+            print_color "$RED" "Error: No .cfg files found in workspace directory '$WORKSPACE_DIR'"
+            exit 1
+        fi
+        for workspace_file in "${workspace_files[@]}"; do
+            check_config_file "$workspace_file"
+            app_namespace="$(basename "$workspace_file" .cfg)::"
+            loaded_config_files=()
+            parse_config_file "$workspace_file"
+        done
+        app_namespace=""
+    else
+        check_config_file "$CONFIG_FILE"
+        CONFIG_DIR="$(cd "$(dirname "$CONFIG_FILE")" && pwd)"
+        parse_config_file "$CONFIG_FILE"
+    fi
+
+    if [[ ${#duplicate_actions[@]} -gt 0 ]]; then
+        print_color "$RED" "Error: Duplicate action names in configuration file '$CONFIG_FILE':"
+        local duplicate
+        for duplicate in "${duplicate_actions[@]}"; do
+            print_color "$RED" "  $duplicate"
+        done
+        print_color "$RED" "Each action name may only appear once per application section."
+        exit 1
+    fi
+    
+    # Group members must be apps defined somewhere in the file
+    local group_name group_member
+    for group_name in "${GROUP_NAMES[@]}"; do
+        while IFS= read -r group_member; do
+            if [[ -z "${APP_ACTION_LIST[$group_member]+set}" ]]; then
+                print_color "$RED" "Error: Group '$group_name' in '$CONFIG_FILE' lists unknown app '$group_member'"
+                exit 1
+            fi
+        done < <(get_group_members "$group_name")
+    done
+    
+    # Dependencies must name existing actions of the same app and must not loop
+    local dependency_key
+    while IFS= read -r dependency_key; do
+        [[ -z "$dependency_key" ]] && continue
+        local dependency_app="${dependency_key%:*}"
+        local dependency_action="${dependency_key##*:}"
+        local dependency_line="${dependency_lines[$dependency_key]}"
+        if [[ -z "${APP_ACTIONS[$dependency_key]+set}" ]]; then
+            print_color "$RED" "Error: '$dependency_action.depends' on $dependency_line refers to unknown action '$dependency_action' in $dependency_app"
+            exit 1
+        fi
+        local dependency
+        for dependency in ${APP_ACTION_DEPS[$dependency_key]}; do
+            if [[ -z "${APP_ACTIONS[$dependency_app:$dependency]+set}" ]]; then
+                print_color "$RED" "Error: Unknown dependency '$dependency' for '$dependency_key' on $dependency_line"
+                exit 1
+            fi
+        done
+        local dependency_cycle
+        if ! dependency_cycle=$(resolve_action_dependencies "$dependency_app" "$dependency_action"); then
+            print_color "$RED" "Error: Dependency cycle in $dependency_app on $dependency_line: $dependency_cycle"
+            exit 1
+        fi
+    done < <(printf '%s\n' "${!APP_ACTION_DEPS[@]}" | sort)
     
     if [[ $CLI_CONTAINER_OVERRIDE -eq 1 ]]; then
         CONTAINER_COMMAND="$CLI_CONTAINER_COMMAND"
@@ -337,6 +1188,17 @@ parse_config() {
         fi
     fi
 
+    # Already running inside a container: per-app container commands are ignored as well
+    if [[ -f "$CONTAINER_ENV_FILE" ]]; then
+        local app_name
+        for app_name in "${!APP_CONTAINER[@]}"; do
+            if [[ -n "${APP_CONTAINER[$app_name]}" ]]; then
+                print_color "$YELLOW" "Detected $CONTAINER_ENV_FILE - ignoring container command for $app_name: ${APP_CONTAINER[$app_name]}"
+            fi
+            unset 'APP_CONTAINER[$app_name]'
+        done
+    fi
+
     if [[ ${#APPS[@]} -eq 0 ]]; then
         print_color "$RED" "Error: No applications found in configuration file!"
         exit 1
@@ -347,17 +1209,22 @@ parse_config() {
 show_app_details() {
     local app="$1"
     local script_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
-    local working_dir="${APP_WORKING_DIR[$app]:-}"
+    local working_dir
+    working_dir=$(resolve_working_dir "$app")
     local log_dir="${APP_LOG_DIR[$app]:-}"
     
-    if [[ -z "$working_dir" ]]; then
-        working_dir="$script_dir (default)"
+    # Show the directory commands actually run in, with the configured value for reference
+    local working_dir_missing=0
+    if [[ -d "$working_dir" ]]; then
+        working_dir=$(cd "$working_dir" && pwd)
     else
-        # Expand tilde and relative paths for display
-        working_dir="${working_dir/#\~/$HOME}"
-        if [[ ! "$working_dir" =~ ^/ ]]; then
-            working_dir="$script_dir/$working_dir"
-        fi
+        working_dir="${working_dir//\/.\//\/}"
+        [[ -z "$(resolve_container_command "$app")" ]] && working_dir_missing=1
+    fi
+    if [[ -z "${APP_WORKING_DIR[$app]:-}" ]]; then
+        working_dir="$working_dir (default)"
+    elif [[ "${APP_WORKING_DIR[$app]}" != "$working_dir" ]]; then
+        working_dir="$working_dir (configured: ${APP_WORKING_DIR[$app]})"
     fi
     
     # Determine effective log directory
@@ -383,11 +1250,43 @@ show_app_details() {
     
     echo
     print_color "$CYAN" "=== $app ==="
+    if [[ -n "${APP_GROUP[$app]:-}" ]]; then
+        echo "Group:          ${APP_GROUP[$app]}"
+    fi
     echo "Working Dir:    $working_dir"
+    if [[ $working_dir_missing -eq 1 ]]; then
+        print_color "$RED" "                Warning: this directory does not exist; actions will fail to start"
+    fi
     echo "Log Dir:        $log_dir"
+    if [[ -n "${APP_SHELL[$app]:-}" ]]; then
+        echo "Shell:          ${APP_SHELL[$app]} (app-specific)"
+    elif [[ -n "$GLOBAL_SHELL" ]]; then
+        echo "Shell:          $GLOBAL_SHELL (global)"
+    else
+        echo "Shell:          bash (default)"
+    fi
+    local timeout
+    timeout=$(resolve_timeout "$app")
+    if [[ -n "$timeout" ]]; then
+        echo "Timeout:        ${timeout}s"
+    else
+        echo "Timeout:        (none)"
+    fi
+    local env_label="Environment:"
+    local env_name
+    for env_name in ${APP_ENV_NAMES[$app]:-}; do
+        printf '%-16s%s\n' "$env_label" "$env_name=${APP_ENV[$app:$env_name]}"
+        env_label=""
+    done
     
     # Show container configuration
-    if [[ -n "$CONTAINER_COMMAND" ]]; then
+    if [[ -n "${APP_CONTAINER[$app]+set}" ]]; then
+        if [[ -n "${APP_CONTAINER[$app]}" ]]; then
+            echo "Container:      ${APP_CONTAINER[$app]} (app-specific)"
+        else
+            echo "Container:      (none - app-specific, runs on host)"
+        fi
+    elif [[ -n "$CONTAINER_COMMAND" ]]; then
         if [[ $CLI_CONTAINER_OVERRIDE -eq 1 ]]; then
             echo "Container:      $CONTAINER_COMMAND (overridden via --container)"
         else
@@ -403,77 +1302,389 @@ show_app_details() {
     print_color "$YELLOW" "Available Actions:"
     
     # Get all actions for this app
-    local actions="${APP_ACTION_LIST[$app]:-}"
-    if [[ -z "$actions" ]]; then
+    local -a actions=()
+    readarray -t actions < <(get_actions "$app")
+    if [[ ${#actions[@]} -eq 0 ]]; then
         echo "  No actions configured"
     else
         # Display each action and its command
-        for action in $actions; do
+        for action in "${actions[@]}"; do
             local command="${APP_ACTIONS[$app:$action]:-}"
             echo
             print_color "$CYAN" "  $action:"
             echo "    Command: $command"
+            if [[ -n "${APP_ACTION_DEPS[$app:$action]:-}" ]]; then
+                local dependency_chain
+                dependency_chain=$(resolve_action_dependencies "$app" "$action" | tr '\n' ' ' | sed 's/ $//; s/ / -> /g')
+                echo "    Depends: $dependency_chain -> $action"
+            fi
             
             # Show how it will be executed (with or without container)
-            if [[ -n "$CONTAINER_COMMAND" ]]; then
-                local working_dir_for_display="${APP_WORKING_DIR[$app]:-}"
-                if [[ -n "$working_dir_for_display" ]]; then
-                    local container_cmd="cd $(printf '%q' "$working_dir_for_display") && $command"
-                    local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                    echo "    Full cmd: $CONTAINER_COMMAND bash -lc $escaped_container_cmd"
-                else
-                    local escaped_command="$(printf '%q' "$command")"
-                    echo "    Full cmd: $CONTAINER_COMMAND bash -lc $escaped_command"
-                fi
-            else
-                echo "    Full cmd: bash -c $(printf '%q' "$command")"
-            fi
+            echo "    Full cmd: $(build_full_command "$app" "$action")"
         done
     fi
     echo
 }
 
+# Function to get the program a container command invokes (skipping leading VAR=value assignments)
+container_runtime_of() {
+    local cmd="$1"
+    local -a words=()
+    read -r -a words <<< "$cmd"
+    
+    local word
+    for word in "${words[@]}"; do
+        if [[ ! "$word" =~ ^[A-Za-z_][A-Za-z0-9_]*= ]]; then
+            echo "$word"
+            return 0
+        fi
+    done
+    return 1
+}
+
+# Function to check whether a container command uses a known container runtime
+container_runtime_is_known() {
+    local runtime
+    runtime=$(container_runtime_of "$1") || return 1
+    runtime="${runtime##*/}"
+    
+    local known
+    for known in $KNOWN_RUNTIMES; do
+        [[ "$runtime" == "$known" ]] && return 0
+    done
+    return 1
+}
+
+# Function to validate a container command before it is used to run anything
+validate_container_command() {
+    local cmd="$1"
+    
+    # The command is spliced into 'bash -c', so it has to be valid shell on its own
+    if ! bash -n -c "$cmd" 2>/dev/null; then
+        print_color "$RED" "Error: Container command has a syntax error (check its quoting): $cmd"
+        return 1
+    fi
+    
+    local runtime
+    if ! runtime=$(container_runtime_of "$cmd"); then
+        print_color "$RED" "Error: Container command does not name a program to run: '$cmd'"
+        return 1
+    fi
+    
+    if ! command -v "$runtime" >/dev/null 2>&1; then
+        print_color "$RED" "Error: Container runtime '$runtime' not found in PATH"
+        return 1
+    fi
+    
+    return 0
+}
+
+# Function to resolve the container command used for an app (empty = run on host)
+# Precedence: per-app container key, then --container, then the global container key
+resolve_container_command() {
+    local app="$1"
+    
+    if [[ -n "${APP_CONTAINER[$app]+set}" ]]; then
+        echo "${APP_CONTAINER[$app]}"
+    else
+        echo "$CONTAINER_COMMAND"
+    fi
+}
+
+# Function to resolve the shell that runs an app's commands (per-app, global, then bash)
+resolve_shell() {
+    local app="$1"
+    echo "${APP_SHELL[$app]:-${GLOBAL_SHELL:-bash}}"
+}
+
+# Function to print the option that makes a shell run a command string
+# Login mode (-l) is only used in containers, and only for shells that support it
+shell_command_flag() {
+    local shell="$1"
+    local login="${2:-false}"
+    case "${shell##*/}" in
+        bash|zsh|ksh|mksh|fish)
+            if [[ "$login" == "true" ]]; then
+                echo "-lc"
+                return
+            fi
+            ;;
+    esac
+    echo "-c"
+}
+
+# Function to resolve the cap on concurrently running actions (0 = no limit)
+resolve_parallel_limit() {
+    local limit="${CLI_PARALLEL_LIMIT:-${CONFIG_PARALLEL_LIMIT:-0}}"
+    echo $((10#$limit))
+}
+
+# Function to count how many of the given job pids are still running
+count_running_jobs() {
+    local running=0
+    local pid
+    for pid in "$@"; do
+        if kill -0 "$pid" 2>/dev/null; then
+            ((running++))
+        fi
+    done
+    echo "$running"
+}
+
+# Function to block until fewer than LIMIT of the given jobs are running (0 = no limit)
+wait_for_job_slot() {
+    local limit="$1"
+    shift
+    if [[ $limit -le 0 ]]; then
+        return 0
+    fi
+    while [[ $(count_running_jobs "$@") -ge $limit ]]; do
+        sleep 0.1
+    done
+}
+
+# Function to resolve the timeout in seconds for an app (prints nothing when there is none)
+resolve_timeout() {
+    local app="$1"
+    local timeout="${APP_TIMEOUT[$app]:-$GLOBAL_TIMEOUT}"
+    
+    if [[ -n "$timeout" && "$timeout" -gt 0 ]]; then
+        echo "$timeout"
+    fi
+}
+
+# Function to build the full command line for an action (as shown to the user)
+build_full_command() {
+    local app="$1"
+    local action="$2"
+    local command="${APP_ACTIONS[$app:$action]:-}"
+    local working_dir="${APP_WORKING_DIR[$app]:-}"
+    local container_command
+    container_command=$(resolve_container_command "$app")
+    local shell
+    shell=$(resolve_shell "$app")
+    
+    # Per-app environment variables as NAME=value words
+    local -a env_assignments=()
+    local env_name
+    for env_name in ${APP_ENV_NAMES[$app]:-}; do
+        env_assignments+=("$env_name=$(printf '%q' "${APP_ENV[$app:$env_name]}")")
+    done
+    
+    if [[ -n "$container_command" ]]; then
+        # Container mode: cd inside the container (working_dir is relative to its starting point)
+        # The runtime does not forward the host environment, so variables are exported inside
+        if [[ ${#env_assignments[@]} -gt 0 ]]; then
+            command="export ${env_assignments[*]} && $command"
+        fi
+        if [[ -n "$working_dir" ]]; then
+            command="cd $(printf '%q' "$working_dir") && $command"
+        fi
+        echo "$container_command $shell $(shell_command_flag "$shell" true) $(printf '%q' "$command")"
+    elif [[ ${#env_assignments[@]} -gt 0 ]]; then
+        echo "env ${env_assignments[*]} $shell -c $(printf '%q' "$command")"
+    else
+        echo "$shell -c $(printf '%q' "$command")"
+    fi
+}
+
+# Function to append the structured timing line to a log file
+# Format: TIMING_V1:app:action:exit_code:duration_ms:start_ts
+append_timing_line() {
+    local log_file="$1"
+    local app="$2"
+    local action="$3"
+    local exit_code="$4"
+    local duration_ms="$5"
+    local start_ts="$6"
+    
+    echo "TIMING_V1:$app:$action:$exit_code:$duration_ms:$start_ts" >> "$log_file"
+}
+
+# Function to record one execution in the history file (one JSON object per line)
+append_history_entry() {
+    local timestamp="$1"
+    local app="$2"
+    local action="$3"
+    local exit_code="$4"
+    local duration_ms="$5"
+    
+    # History is best effort: an unwritable data directory must not fail the action
+    mkdir -p "$(dirname "$HISTORY_FILE")" 2>/dev/null || return 0
+    printf '{"timestamp": "%s", "app": "%s", "action": "%s", "exit_code": %d, "duration_ms": %d}\n' \
+        "$timestamp" "$(json_escape "$app")" "$(json_escape "$action")" "$exit_code" "$duration_ms" \
+        >> "$HISTORY_FILE" 2>/dev/null || true
+}
+
+# Function to print the last entries of the history file (--history)
+print_history() {
+    local limit="$1"
+    
+    if [[ ! -s "$HISTORY_FILE" ]]; then
+        echo "No executions recorded yet ($HISTORY_FILE)"
+        return 0
+    fi
+    
+    local entry
+    local string_field='"((\\.|[^"\\])*)"'
+    while IFS= read -r entry; do
+        local timestamp="" app="" action="" exit_code="" duration_ms=""
+        [[ "$entry" =~ \"timestamp\":\ $string_field ]] && timestamp="${BASH_REMATCH[1]}"
+        [[ "$entry" =~ \"app\":\ $string_field ]] && app="${BASH_REMATCH[1]}"
+        [[ "$entry" =~ \"action\":\ $string_field ]] && action="${BASH_REMATCH[1]}"
+        [[ "$entry" =~ \"exit_code\":\ ([0-9]+) ]] && exit_code="${BASH_REMATCH[1]}"
+        [[ "$entry" =~ \"duration_ms\":\ ([0-9]+) ]] && duration_ms="${BASH_REMATCH[1]}"
+        [[ -z "$exit_code" ]] && continue
+        app="${app//\\\"/\"}"; app="${app//\\\\/\\}"
+        action="${action//\\\"/\"}"; action="${action//\\\\/\\}"
+        if [[ $exit_code -eq 0 ]]; then
+            print_color "$GREEN" "$timestamp  ✅ $app - $action ($(format_duration "${duration_ms:-0}"))"
+        else
+            print_color "$RED" "$timestamp  ❌ $app - $action (exit code $exit_code, $(format_duration "${duration_ms:-0}"))"
+        fi
+    done < <(tail -n "$limit" "$HISTORY_FILE")
+}
+
+# Function to run an action's command and return its exit code
+# Modes: "terminal" prints output only, "tee" prints and logs, "log" only logs
+run_action_command() {
+    local app="$1"
+    local action="$2"
+    local mode="$3"
+    local log_file="${4:-}"
+    local command="${APP_ACTIONS[$app:$action]:-}"
+    local working_dir=""
+    local -a runner=()
+    
+    if [[ -n "$(resolve_container_command "$app")" ]]; then
+        runner=(bash -c "$(build_full_command "$app" "$action")")
+    else
+        working_dir=$(resolve_working_dir "$app")
+        runner=("$(resolve_shell "$app")" -c "$command")
+        local env_name
+        for env_name in ${APP_ENV_NAMES[$app]:-}; do
+            runner=("$env_name=${APP_ENV[$app:$env_name]}" "${runner[@]}")
+        done
+        if [[ -n "${APP_ENV_NAMES[$app]:-}" ]]; then
+            runner=(env "${runner[@]}")
+        fi
+    fi
+    
+    local start_ts
+    start_ts=$(date +%s)
+    local start_time
+    start_time=$(date -u +%Y-%m-%dT%H:%M:%SZ)
+    local start_ms
+    start_ms=$(current_time_ms)
+    
+    # With a timeout, a watchdog kills the command's process tree once the deadline passes.
+    # The command itself stays in the foreground so it keeps the terminal as stdin.
+    local timeout_seconds
+    timeout_seconds=$(resolve_timeout "$app")
+    local timeout_state_dir=""
+    local watchdog_pid=""
+    if [[ -n "$timeout_seconds" ]]; then
+        timeout_state_dir=$(mktemp -d 2>/dev/null || mktemp -d -t shell-bun)
+        (
+            sleep "$timeout_seconds"
+            local command_pid
+            command_pid=$(cat "$timeout_state_dir/pid" 2>/dev/null) || exit 0
+            : > "$timeout_state_dir/timed_out"
+            terminate_process_tree "$command_pid"
+        ) &
+        watchdog_pid=$!
+    fi
+    
+    local exit_code
+    case "$mode" in
+        terminal)
+            (record_command_pid "$timeout_state_dir"; cd "${working_dir:-.}" && "${runner[@]}")
+            exit_code=$?
+            ;;
+        tee)
+            (record_command_pid "$timeout_state_dir"; cd "${working_dir:-.}" && "${runner[@]}" 2>&1 | tee "$log_file"; exit "${PIPESTATUS[0]}")
+            exit_code=$?
+            ;;
+        log)
+            (record_command_pid "$timeout_state_dir"; cd "${working_dir:-.}" && "${runner[@]}" > "$log_file" 2>&1)
+            exit_code=$?
+            ;;
+    esac
+    
+    if [[ -n "$watchdog_pid" ]]; then
+        terminate_process_tree "$watchdog_pid"
+        wait "$watchdog_pid" 2>/dev/null
+        if [[ -f "$timeout_state_dir/timed_out" ]]; then
+            exit_code=$TIMEOUT_EXIT_CODE
+            local timeout_message="⏱️  Timed out after ${timeout_seconds}s: $app - $action (exit code $TIMEOUT_EXIT_CODE)"
+            if [[ -n "$log_file" ]]; then
+                echo "$timeout_message" >> "$log_file"
+            fi
+            if [[ "$mode" != "log" ]]; then
+                print_color "$RED" "$timeout_message"
+            fi
+        fi
+        rm -rf "$timeout_state_dir"
+    fi
+    
+    local duration_ms=$(($(current_time_ms) - start_ms))
+    if [[ -n "$log_file" ]]; then
+        append_timing_line "$log_file" "$app" "$action" "$exit_code" "$duration_ms" "$start_ts"
+    fi
+    append_history_entry "$start_time" "$app" "$action" "$exit_code" "$duration_ms"
+    
+    return $exit_code
+}
+
 # Function to execute command
 execute_command() {
     local app="$1"
     local action="$2"
     local show_output="${3:-false}"  # New parameter: whether to show output in terminal
     local log_file_var="$4"          # Variable name to store log file path
+    local skip_dependencies="${5:-false}" # Set when called for a dependency that was already resolved
     local command="${APP_ACTIONS[$app:$action]:-}"
     local action_name="$action"
     
+    # Run prerequisite actions (ACTION.depends) first; a failing one stops the chain
+    if [[ "$skip_dependencies" != "true" && -n "${APP_ACTION_DEPS[$app:$action]:-}" ]]; then
+        local dependency dependency_log_file dependency_exit_code
+        local -a dependencies=()
+        readarray -t dependencies < <(resolve_action_dependencies "$app" "$action")
+        for dependency in "${dependencies[@]}"; do
+            dependency_log_file=""
+            execute_command "$app" "$dependency" "$show_output" "dependency_log_file" "true"
+            dependency_exit_code=$?
+            if [[ $dependency_exit_code -ne 0 ]]; then
+                print_color "$RED" "Error: Dependency '$dependency' of $app - $action failed, not running $action"
+                log_execution "$app" "$action_name" "error"
+                # Point the caller at the log that explains the failure
+                if [[ -n "$log_file_var" ]]; then
+                    printf -v "$log_file_var" '%s' "$dependency_log_file"
+                fi
+                return $dependency_exit_code
+            fi
+        done
+    fi
+    
     if [[ -z "$command" ]]; then
         log_execution "$app" "$action_name" "error"
         print_color "$RED" "Error: No command configured for '$action' in $app"
         return 1
     fi
     
-    # Get working directory - default to script directory if not specified
-    local working_dir="${APP_WORKING_DIR[$app]:-}"
-    local working_dir_for_container="$working_dir"  # Store original for container use
-    local script_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+    local container_command
+    container_command=$(resolve_container_command "$app")
+    if [[ -n "$container_command" ]] && ! validate_container_command "$container_command"; then
+        log_execution "$app" "$action_name" "error"
+        return 1
+    fi
     
     # When using container, working_dir is relative to the container's starting point
-    # When not using container, working_dir is relative to the script directory
-    if [[ -n "$CONTAINER_COMMAND" ]]; then
-        # Container mode: use working_dir as-is (relative to container's starting point)
-        # If no working_dir specified, don't cd at all in the container
-        if [[ -z "$working_dir_for_container" ]]; then
-            working_dir_for_container=""
-        fi
-    else
-        # Non-container mode: resolve paths relative to script directory
-        if [[ -z "$working_dir" ]]; then
-            working_dir="$script_dir"
-        fi
-        
-        # Expand tilde in working_dir if present
-        working_dir="${working_dir/#\~/$HOME}"
-        
-        # Make relative paths relative to script directory
-        if [[ ! "$working_dir" =~ ^/ ]]; then
-            working_dir="$script_dir/$working_dir"
-        fi
+    # When not using container, working_dir is relative to the script (or config) directory
+    if [[ -z "$container_command" ]]; then
+        local working_dir
+        working_dir=$(resolve_working_dir "$app")
         
         # Check if working directory exists (only for non-container mode)
         if [[ ! -d "$working_dir" ]]; then
@@ -481,84 +1692,45 @@ execute_command() {
             print_color "$RED" "Error: Working directory '$working_dir' does not exist for $app"
             return 1
         fi
+        
+        local shell
+        shell=$(resolve_shell "$app")
+        if ! command -v "$shell" >/dev/null 2>&1; then
+            log_execution "$app" "$action_name" "error"
+            print_color "$RED" "Error: Shell '$shell' for $app was not found on PATH"
+            return 1
+        fi
     fi
     
-    # Generate log file path (unless in CI mode)
+    # Generate log file path (unless in CI mode; JSON results report the log file, so keep one there)
     local log_file=""
-    if [[ $CI_MODE -eq 0 ]]; then
+    if [[ $CI_MODE -eq 0 || "$OUTPUT_FORMAT" == "json" ]]; then
         log_file=$(generate_log_file_path "$app" "$action")
         # Store log file path in the provided variable name
         if [[ -n "$log_file_var" ]]; then
-            declare -g "$log_file_var=$log_file"
-        fi
-    fi
-    
-    # Build the full command that will be executed (for display purposes)
-    local full_command_display
-    local escaped_command="$(printf '%q' "$command")"
-    if [[ -n "$CONTAINER_COMMAND" ]]; then
-        if [[ -n "$working_dir_for_container" ]]; then
-            local container_cmd="cd $(printf '%q' "$working_dir_for_container") && $command"
-            local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-            full_command_display="$CONTAINER_COMMAND bash -lc $escaped_container_cmd"
-        else
-            full_command_display="$CONTAINER_COMMAND bash -lc $escaped_command"
+            printf -v "$log_file_var" '%s' "$log_file"
         fi
-    else
-        full_command_display="bash -c $escaped_command"
     fi
     
-    log_execution "$app" "$action_name" "start" "$full_command_display"
+    log_execution "$app" "$action_name" "start" "$(build_full_command "$app" "$action")"
     
     # Execute the command in a subshell with proper working directory
     local exit_code
-    local escaped_command="$(printf '%q' "$command")"
-
-    if [[ $CI_MODE -eq 1 ]]; then
+    if [[ $CI_MODE -eq 1 && -n "$log_file" ]]; then
+        # CI mode with JSON results: print to terminal and keep the log file it reports
+        run_action_command "$app" "$action" "tee" "$log_file"
+        exit_code=$?
+    elif [[ $CI_MODE -eq 1 ]]; then
         # CI mode: just print to terminal
-        if [[ -n "$CONTAINER_COMMAND" ]]; then
-            # Container mode: cd inside the container
-            if [[ -n "$working_dir_for_container" ]]; then
-                local container_cmd="cd $(printf '%q' "$working_dir_for_container") && $command"
-                local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_container_cmd")
-            else
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_command")
-            fi
-        else
-            (cd "$working_dir" && bash -c "$command")
-        fi
+        run_action_command "$app" "$action" "terminal"
         exit_code=$?
     elif [[ "$show_output" == "true" ]]; then
         # Interactive single execution: show output and log to file
-        if [[ -n "$CONTAINER_COMMAND" ]]; then
-            # Container mode: cd inside the container
-            if [[ -n "$working_dir_for_container" ]]; then
-                local container_cmd="cd $(printf '%q' "$working_dir_for_container") && $command"
-                local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_container_cmd" 2>&1 | tee "$log_file")
-            else
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_command" 2>&1 | tee "$log_file")
-            fi
-            exit_code=${PIPESTATUS[0]}
-        else
-            (cd "$working_dir" && bash -c "$command" 2>&1 | tee "$log_file")
-            exit_code=${PIPESTATUS[0]}
-        fi
+        run_action_command "$app" "$action" "tee" "$log_file"
+        exit_code=$?
     else
         # Interactive parallel execution: only log to file
-        if [[ -n "$CONTAINER_COMMAND" ]]; then
-            # Container mode: cd inside the container
-            if [[ -n "$working_dir_for_container" ]]; then
-                local container_cmd="cd $(printf '%q' "$working_dir_for_container") && $command"
-                local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_container_cmd" > "$log_file" 2>&1)
-            else
-                (bash -c "$CONTAINER_COMMAND bash -lc $escaped_command" > "$log_file" 2>&1)
-            fi
-        else
-            (cd "$working_dir" && bash -c "$command" > "$log_file" 2>&1)
-        fi
+        run_action_command "$app" "$action" "log" "$log_file"
         exit_code=$?
     fi
     
@@ -570,8 +1742,51 @@ execute_command() {
         if [[ $CI_MODE -eq 1 ]]; then
             print_color "$RED" "Command failed with exit code $exit_code"
         fi
+        return $exit_code
+    fi
+}
+
+# Function to open an app's log directory in the system file manager
+open_log_directory() {
+    local app="$1"
+    local log_dir
+    log_dir=$(resolve_log_dir "$app")
+    
+    # Create the directory first so there is something to open
+    if ! mkdir -p "$log_dir" 2>/dev/null; then
+        print_color "$RED" "Error: Cannot create log directory '$log_dir'"
+        return 1
+    fi
+    
+    local opener=""
+    case "$(uname -s 2>/dev/null)" in
+        Darwin)
+            opener="open"
+            ;;
+        MINGW*|MSYS*|CYGWIN*)
+            opener="explorer"
+            ;;
+        *)
+            if command -v xdg-open >/dev/null 2>&1; then
+                opener="xdg-open"
+            elif command -v explorer.exe >/dev/null 2>&1; then
+                # WSL without a Linux desktop: hand the folder to Windows Explorer
+                opener="explorer.exe"
+                log_dir=$(wslpath -w "$log_dir" 2>/dev/null || echo "$log_dir")
+            fi
+            ;;
+    esac
+    
+    if [[ -z "$opener" ]] || ! command -v "$opener" >/dev/null 2>&1; then
+        print_color "$RED" "Error: No file manager launcher found (tried xdg-open, open, explorer)"
+        echo "Log directory: $log_dir"
         return 1
     fi
+    
+    debug_log "Opening log directory with $opener: $log_dir"
+    # Detach so the file manager does not hold on to the menu's terminal
+    ("$opener" "$log_dir" >/dev/null 2>&1 &)
+    return 0
 }
 
 # Function to execute a single command
@@ -582,12 +1797,112 @@ execute_single() {
     print_color "$BLUE" "📦 Executing: $app - $action"
     echo
     
-    local log_file=""
-    execute_command "$app" "$action" "true" "log_file"
+    local single_log_file=""
+    execute_command "$app" "$action" "true" "single_log_file"
     
-    echo
-    echo "Press Enter to continue..."
-    read
+    # Review the output in the log pager; fall back to a prompt when there is nothing to page
+    if [[ -t 0 && -n "$single_log_file" && -f "$single_log_file" ]]; then
+        show_log_file "$single_log_file" "$app - $action"
+    else
+        echo
+        echo "Press Enter to continue..."
+        read
+    fi
+}
+
+# Function to page through a log file inside the TUI
+# Keys: ↑/↓ or j/k scroll, PgUp/PgDn page, g/G top/bottom, f follow (keep re-reading the end), q/ESC close
+show_log_file() {
+    local log_file="$1"
+    local title="${2:-$log_file}"
+    local follow=false
+    local top=-1 # First visible line; -1 starts at the end like "less +G"
+    local needs_redraw=true
+    local last_signature=""
+    local -a log_lines=()
+    local body_height=1
+    
+    printf '\033[?25l' # Hide cursor
+    clear
+    
+    while true; do
+        if [[ "$needs_redraw" == "true" ]]; then
+            needs_redraw=false
+            # Skip the machine-readable timing records appended by append_timing_line
+            readarray -t log_lines < <(grep -v '^TIMING_V1:' "$log_file" 2>/dev/null)
+            last_signature=$(config_file_signature "$log_file")
+            
+            local terminal_height terminal_width
+            terminal_height=$(tput lines 2>/dev/null || echo 24)
+            terminal_width=$(tput cols 2>/dev/null || echo 80)
+            body_height=$((terminal_height - 3)) # header, blank line, footer
+            if [[ $body_height -lt 1 ]]; then body_height=1; fi
+            
+            local total=${#log_lines[@]}
+            local max_top=$((total - body_height))
+            if [[ $max_top -lt 0 ]]; then max_top=0; fi
+            if [[ "$follow" == "true" || $top -lt 0 || $top -gt $max_top ]]; then
+                top=$max_top
+            fi
+            
+            local last_shown=$((top + body_height))
+            if [[ $last_shown -gt $total ]]; then last_shown=$total; fi
+            
+            printf '\033[H' # Redraw in place to avoid flicker
+            local header="📄 $title  (lines $((total > 0 ? top + 1 : 0))-$last_shown of $total)"
+            if [[ "$follow" == "true" ]]; then
+                header="$header  [FOLLOW]"
+            fi
+            printf '%b\033[K\n' "${CYAN}${header}${NC}"
+            printf '\033[K\n'
+            local i
+            for ((i = top; i < top + body_height; i++)); do
+                if [[ $i -lt $total ]]; then
+                    # Clip to the terminal width so long lines do not wrap and break the layout
+                    printf '%s\033[0m\033[K\n' "${log_lines[$i]:0:$terminal_width}"
+                else
+                    printf '\033[K\n'
+                fi
+            done
+            printf '%b\033[K' "${DIM}↑/↓ j/k: scroll | PgUp/PgDn: page | g/G: top/bottom | f: follow | q: close${NC}"
+        fi
+        
+        local key=""
+        IFS= read -rsn1 -t 0.5 key 2>/dev/null
+        if [[ $? -gt 128 ]]; then
+            # No key: redraw only when the file changed (follow mode) or the terminal was resized
+            if [[ "$follow" == "true" && "$(config_file_signature "$log_file")" != "$last_signature" ]]; then
+                needs_redraw=true
+            elif [[ $((body_height + 3)) -ne $(tput lines 2>/dev/null || echo 24) ]]; then
+                needs_redraw=true
+            fi
+            continue
+        fi
+        
+        needs_redraw=true
+        case "$key" in
+            $'\x1b')
+                local sequence=""
+                read -rsn2 -t 0.1 sequence 2>/dev/null
+                case "$sequence" in
+                    "[A") ((top > 0)) && ((top--)); follow=false ;;
+                    "[B") ((top++)) ;;
+                    "[5") read -rsn1 -t 0.1 _ 2>/dev/null; top=$((top - body_height)); ((top < 0)) && top=0; follow=false ;;
+                    "[6") read -rsn1 -t 0.1 _ 2>/dev/null; top=$((top + body_height)) ;;
+                    "") break ;; # Plain ESC closes the pager
+                esac
+                ;;
+            k) ((top > 0)) && ((top--)); follow=false ;;
+            j) ((top++)) ;;
+            g) top=0; follow=false ;;
+            G) top=${#log_lines[@]} ;;
+            f|F) if [[ "$follow" == "true" ]]; then follow=false; else follow=true; fi ;;
+            q|Q) break ;;
+            *) needs_redraw=false ;;
+        esac
+    done
+    
+    clear
 }
 
 # Function to show log viewer menu
@@ -805,12 +2120,116 @@ show_log_viewer() {
     done
 }
 
-# Function to execute multiple commands in parallel
+# Function to send a signal to a process and all of its descendants
+signal_process_tree() {
+    local signal="$1"
+    local pid="$2"
+    
+    # Signal the parent first so a stopped parent cannot spawn new children
+    kill -"$signal" "$pid" 2>/dev/null || return 0
+    
+    local child
+    for child in $(pgrep -P "$pid" 2>/dev/null); do
+        signal_process_tree "$signal" "$child"
+    done
+}
+
+# Function to list a process and all of its descendants, one pid per line
+list_process_tree() {
+    local pid="$1"
+    
+    echo "$pid"
+    local child
+    for child in $(pgrep -P "$pid" 2>/dev/null); do
+        list_process_tree "$child"
+    done
+}
+
+# Function to terminate a process and all of its descendants
+terminate_process_tree() {
+    local pid="$1"
+    
+    # Collect the whole tree before signalling so children are not lost to reparenting
+    local -a tree_pids=()
+    readarray -t tree_pids < <(list_process_tree "$pid")
+    kill -TERM "${tree_pids[@]}" 2>/dev/null
+    return 0
+}
+
+# Function to record the current subshell's pid for the timeout watchdog (no-op without a timeout)
+record_command_pid() {
+    local state_dir="$1"
+    
+    if [[ -n "$state_dir" ]]; then
+        echo "$BASHPID" > "$state_dir/pid"
+    fi
+}
+
+# Function to wait for background jobs while letting the user pause/resume them with 'p'
+wait_with_pause_control() {
+    local show_hint=true
+    if [[ "${1:-}" == "--no-hint" ]]; then
+        show_hint=false
+        shift
+    fi
+    local -a job_pids=("$@")
+    local paused=false
+    
+    # Without a terminal there is nobody to press keys; the caller's wait does the work
+    if [[ ! -t 0 || ${#job_pids[@]} -eq 0 ]]; then
+        return 0
+    fi
+    
+    if [[ "$show_hint" == "true" ]]; then
+        print_color "$DIM" "Press 'p' to pause/resume running jobs"
+    fi
+    
+    while true; do
+        local -a running_pids=()
+        local pid
+        for pid in "${job_pids[@]}"; do
+            if kill -0 "$pid" 2>/dev/null; then
+                running_pids+=("$pid")
+            fi
+        done
+        if [[ ${#running_pids[@]} -eq 0 ]]; then
+            break
+        fi
+        
+        local key=""
+        IFS= read -rsn1 -t 0.2 key 2>/dev/null || true
+        if [[ "$key" == "p" || "$key" == "P" ]]; then
+            if [[ "$paused" == "true" ]]; then
+                for pid in "${running_pids[@]}"; do
+                    signal_process_tree CONT "$pid"
+                done
+                paused=false
+                debug_log "Resumed ${#running_pids[@]} job(s)"
+                print_color "$GREEN" "▶️  Resumed ${#running_pids[@]} job(s)"
+            else
+                for pid in "${running_pids[@]}"; do
+                    signal_process_tree STOP "$pid"
+                done
+                paused=true
+                debug_log "Paused ${#running_pids[@]} job(s)"
+                print_color "$YELLOW" "⏸️  [PAUSED] ${#running_pids[@]} job(s) stopped - press 'p' to resume"
+            fi
+        fi
+    done
+}
+
+# Function to execute the selected commands, in parallel by default
+# Pass "sequential" to start each command only after the previous one has finished
 execute_parallel() {
+    local run_mode="${1:-parallel}"
     local -a pids=()
+    local -a exit_codes=() # Filled in as each command finishes in sequential mode
     local -a command_names=()
     local -a log_files=()
     local total=0
+    local parallel_limit
+    parallel_limit=$(resolve_parallel_limit)
+    local peak_concurrency=0
     if selected_items_defined; then
         total=${#SELECTED_ITEMS[@]}
     fi
@@ -820,7 +2239,15 @@ execute_parallel() {
         return
     fi
     
-    print_color "$BLUE" "📦 Executing $total selected items in parallel..."
+    if [[ "$run_mode" == "sequential" ]]; then
+        print_color "$BLUE" "📦 Executing $total selected items sequentially..."
+    else
+        if [[ $parallel_limit -gt 0 && $parallel_limit -lt $total ]]; then
+            print_color "$BLUE" "📦 Executing $total selected items in parallel (at most $parallel_limit at a time)..."
+        else
+            print_color "$BLUE" "📦 Executing $total selected items in parallel..."
+        fi
+    fi
     echo
     
     # Clear previous execution results
@@ -837,26 +2264,12 @@ execute_parallel() {
                 local app="${BASH_REMATCH[1]}"
                 local action="${BASH_REMATCH[2]}"
 
-                # Get and display the command
-                local command="${APP_ACTIONS[$app:$action]:-}"
-
-                # Build the full command that will be executed (for display purposes)
-                local working_dir_for_display="${APP_WORKING_DIR[$app]:-}"
-                local full_command_display
-                local escaped_command="$(printf '%q' "$command")"
-                if [[ -n "$CONTAINER_COMMAND" ]]; then
-                    if [[ -n "$working_dir_for_display" ]]; then
-                        local container_cmd="cd $(printf '%q' "$working_dir_for_display") && $command"
-                        local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                        full_command_display="$CONTAINER_COMMAND bash -lc $escaped_container_cmd"
-                    else
-                        full_command_display="$CONTAINER_COMMAND bash -lc $escaped_command"
-                    fi
-                else
-                    full_command_display="bash -c $escaped_command"
+                # With a --parallel/parallel= cap, wait for a running job to finish first
+                if [[ "$run_mode" != "sequential" ]]; then
+                    wait_for_job_slot "$parallel_limit" "${pids[@]}"
                 fi
 
-                log_execution "$app" "$action" "start" "$full_command_display"
+                log_execution "$app" "$action" "start" "$(build_full_command "$app" "$action")"
 
                 # Generate log file path
                 local log_file=$(generate_log_file_path "$app" "$action")
@@ -864,69 +2277,70 @@ execute_parallel() {
 
                 # Start command in background, redirecting to log file
                 (
-                    # Get working directory
-                    local working_dir="${APP_WORKING_DIR[$app]:-}"
-                    local working_dir_for_container="$working_dir"  # Store original for container use
-                    local script_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
-
-                    # When using container, working_dir is relative to the container's starting point
-                    # When not using container, working_dir is relative to the script directory
-                    if [[ -n "$CONTAINER_COMMAND" ]]; then
-                        # Container mode: use working_dir as-is (relative to the container's starting point)
-                        # If no working_dir specified, don't cd at all in the container
-                        if [[ -z "$working_dir_for_container" ]]; then
-                            working_dir_for_container=""
-                        fi
-                    else
-                        # Non-container mode: resolve paths relative to the script directory
-                        if [[ -z "$working_dir" ]]; then
-                            working_dir="$script_dir"
-                        fi
-
-                        # Expand tilde in working_dir if present
-                        working_dir="${working_dir/#\~/$HOME}"
-
-                        # Make relative paths relative to script directory
-                        if [[ ! "$working_dir" =~ ^/ ]]; then
-                            working_dir="$script_dir/$working_dir"
-                        fi
+                    local command="${APP_ACTIONS[$app:$action]:-}"
+                    if [[ -z "$command" ]]; then
+                        echo "Error: Command not found" > "$log_file" 2>&1
+                        exit 1
                     fi
 
-                    # Execute command
-                    local command="${APP_ACTIONS[$app:$action]:-}"
-                    if [[ -n "$CONTAINER_COMMAND" ]]; then
-                        # Container mode: validate command exists and execute with cd inside container
-                        if [[ -n "$command" ]]; then
-                            local escaped_command="$(printf '%q' "$command")"
-                            if [[ -n "$working_dir_for_container" ]]; then
-                                local container_cmd="cd $(printf '%q' "$working_dir_for_container") && $command"
-                                local escaped_container_cmd="$(printf '%q' "$container_cmd")"
-                                bash -c "$CONTAINER_COMMAND bash -lc $escaped_container_cmd" > "$log_file" 2>&1
-                            else
-                                bash -c "$CONTAINER_COMMAND bash -lc $escaped_command" > "$log_file" 2>&1
-                            fi
-                        else
-                            echo "Error: Command not found" > "$log_file" 2>&1
-                            exit 1
-                        fi
-                    else
-                        # Non-container mode: validate command and working directory exist
-                        if [[ -n "$command" && -d "$working_dir" ]]; then
-                            cd "$working_dir" && bash -c "$command" > "$log_file" 2>&1
-                        else
-                            echo "Error: Command not found or working directory invalid" > "$log_file" 2>&1
+                    local container_command
+                    container_command=$(resolve_container_command "$app")
+                    if [[ -n "$container_command" ]]; then
+                        # Container mode: validate the container command before using it
+                        if ! validate_container_command "$container_command" > "$log_file" 2>&1; then
                             exit 1
                         fi
+                    elif [[ ! -d "$(resolve_working_dir "$app")" ]]; then
+                        # Non-container mode: the working directory must exist on the host
+                        echo "Error: Command not found or working directory invalid" > "$log_file" 2>&1
+                        exit 1
                     fi
+
+                    # Prerequisites (ACTION.depends) run first, each with its own log file
+                    local dependency dependency_log_file
+                    local -a dependencies=()
+                    readarray -t dependencies < <(resolve_action_dependencies "$app" "$action")
+                    for dependency in "${dependencies[@]}"; do
+                        dependency_log_file=""
+                        execute_command "$app" "$dependency" "false" "dependency_log_file" "true" > /dev/null
+                        local dependency_exit_code=$?
+                        if [[ $dependency_exit_code -ne 0 ]]; then
+                            echo "Error: Dependency '$dependency' of $app - $action failed, not running $action (see $dependency_log_file)" > "$log_file"
+                            exit $dependency_exit_code
+                        fi
+                    done
+
+                    run_action_command "$app" "$action" "log" "$log_file"
                 ) &
 
                 pids+=($!)
                 command_names+=("$item")
                 ((counter++))
+                local running_jobs
+                running_jobs=$(count_running_jobs "${pids[@]}")
+                if [[ $running_jobs -gt $peak_concurrency ]]; then
+                    peak_concurrency=$running_jobs
+                fi
+                
+                if [[ "$run_mode" == "sequential" ]]; then
+                    # Finish this command before starting the next one
+                    if [[ $counter -eq 1 ]]; then
+                        wait_with_pause_control "$!"
+                    else
+                        wait_with_pause_control --no-hint "$!"
+                    fi
+                    wait "$!"
+                    exit_codes+=($?)
+                fi
             fi
         done
     fi
     
+    # Let the user pause/resume the jobs until they have all finished
+    if [[ "$run_mode" != "sequential" ]]; then
+        wait_with_pause_control "${pids[@]}"
+    fi
+    
     # Wait for all background processes and track which ones failed
     local success_count=0
     local failure_count=0
@@ -936,8 +2350,13 @@ execute_parallel() {
         local pid="${pids[$i]}"
         local cmd_name="${command_names[$i]}"
         local log_file_path="${log_files[$i]}"
+        local exit_code="${exit_codes[$i]:-}"
+        if [[ -z "$exit_code" ]]; then
+            wait "$pid"
+            exit_code=$?
+        fi
         
-        if wait "$pid"; then
+        if [[ $exit_code -eq 0 ]]; then
             ((success_count++))
             EXECUTION_RESULTS+=("SUCCESS: $cmd_name ($log_file_path)")
             log_execution "${cmd_name%% - *}" "${cmd_name##* - }" "success"
@@ -949,11 +2368,21 @@ execute_parallel() {
         fi
     done
     
+    # The saved selection has done its job once everything succeeded
+    if [[ $failure_count -eq 0 ]]; then
+        rm -f "$SESSION_FILE" 2>/dev/null
+    fi
+    
     # Only show summary if more than one action was executed
     if [[ ${#pids[@]} -gt 1 ]]; then
         echo
         print_color "$BOLD" "📊 Execution Summary:"
         print_color "$GREEN" "✅ Successful: $success_count"
+        if [[ "$run_mode" != "sequential" ]]; then
+            local concurrency_note=""
+            if [[ $parallel_limit -gt 0 ]]; then concurrency_note=" (limit $parallel_limit)"; fi
+            print_color "$CYAN" "⚡ Peak concurrency: $peak_concurrency$concurrency_note"
+        fi
         if [[ $failure_count -gt 0 ]]; then
             print_color "$RED" "❌ Failed: $failure_count"
             if [[ ${#failed_commands[@]} -gt 0 ]]; then
@@ -1015,18 +2444,63 @@ toggle_selection() {
     debug_log "Final SELECTED_ITEMS: $(selected_items_debug_view)"
 }
 
+# Function to save the current selection so it survives quitting the menu
+# Entries are stored as app:action together with the config file path and signature
+save_session() {
+    if ! selected_items_defined || [[ ${#SELECTED_ITEMS[@]} -eq 0 ]]; then
+        rm -f "$SESSION_FILE" 2>/dev/null
+        return 0
+    fi
+    
+    local item
+    {
+        echo "config=$CONFIG_DIR/$(basename "$CONFIG_FILE")"
+        echo "signature=$(config_file_signature "$CONFIG_FILE")"
+        for item in "${SELECTED_ITEMS[@]}"; do
+            if [[ "$item" =~ ^(.+)\ -\ (.+)$ ]]; then
+                echo "selected=${BASH_REMATCH[1]}:${BASH_REMATCH[2]}"
+            fi
+        done
+    } > "$SESSION_FILE" 2>/dev/null
+}
+
+# Function to print the menu items saved by save_session, one per line
+# Returns 1 if there is no session for this config or the config changed since it was saved
+load_session_items() {
+    [[ -f "$SESSION_FILE" ]] || return 1
+    
+    local line session_config="" session_signature=""
+    local -a session_items=()
+    while IFS= read -r line; do
+        case "$line" in
+            config=*) session_config="${line#config=}" ;;
+            signature=*) session_signature="${line#signature=}" ;;
+            selected=*)
+                local entry="${line#selected=}"
+                local app="${entry%:*}"
+                local action="${entry##*:}"
+                if [[ -n "${APP_ACTIONS[$app:$action]+set}" ]]; then
+                    session_items+=("$app - $action")
+                fi
+                ;;
+        esac
+    done < "$SESSION_FILE"
+    
+    if [[ "$session_config" != "$CONFIG_DIR/$(basename "$CONFIG_FILE")" ||
+          "$session_signature" != "$(config_file_signature "$CONFIG_FILE")" ||
+          ${#session_items[@]} -eq 0 ]]; then
+        return 1
+    fi
+    printf '%s\n' "${session_items[@]}"
+}
+
 # Function to select all actionable items
 select_all() {
     SELECTED_ITEMS=()
-    for app in "${APPS[@]}"; do
-        # Get all actions for this app
-        local actions="${APP_ACTION_LIST[$app]:-}"
-        if [[ -n "$actions" ]]; then
-            for action in $actions; do
-                SELECTED_ITEMS+=("$app - $action")
-            done
-        fi
-    done
+    local app action
+    while IFS=$'\t' read -r app action; do
+        SELECTED_ITEMS+=("$app - $action")
+    done < <(all_action_pairs)
 }
 
 # Function to clear all selections
@@ -1039,8 +2513,8 @@ select_filtered() {
     local -a filtered_items=("$@")
     
     for item in "${filtered_items[@]}"; do
-        # Skip "Show Details" items and only select actionable items
-        if [[ ! "$item" =~ -\ Show\ Details$ ]]; then
+        # Skip "Show Details" items and group headers, only select actionable items
+        if [[ ! "$item" =~ -\ Show\ Details$ ]] && ! menu_item_group "$item" >/dev/null; then
             # Check if item is not already selected
             if ! is_selected "$item"; then
                 SELECTED_ITEMS+=("$item")
@@ -1076,6 +2550,50 @@ deselect_filtered() {
     SELECTED_ITEMS=("${new_selected[@]}")
 }
 
+# Function to list the interactive menu entries ("App - action" and "App - Show Details"), one per line
+build_menu_items() {
+    local app action member
+    local -A listed_apps=()
+    for app in "${APPS[@]}"; do
+        [[ -n "${listed_apps[$app]:-}" ]] && continue
+        local -a item_apps=("$app")
+        if [[ -n "${APP_GROUP[$app]:-}" ]]; then
+            # First app of a group: emit the group header followed by all of its members
+            group_header_item "${APP_GROUP[$app]}"
+            readarray -t item_apps < <(get_group_members "${APP_GROUP[$app]}")
+        fi
+        for member in "${item_apps[@]}"; do
+            listed_apps["$member"]=1
+            while IFS= read -r action; do
+                echo "$member - $action"
+            done < <(get_actions "$member")
+            echo "$member - Show Details"
+        done
+    done
+}
+
+# Function to print the menu header item of a group
+group_header_item() {
+    local group="$1"
+    local -a members=()
+    readarray -t members < <(get_group_members "$group")
+    if [[ ${#members[@]} -eq 1 ]]; then
+        echo "Group: $group (1 app)"
+    else
+        echo "Group: $group (${#members[@]} apps)"
+    fi
+}
+
+# Function to print the group of a menu header item (returns 1 if the item is not a group header)
+menu_item_group() {
+    local item="$1"
+    if [[ "$item" =~ ^Group:\ (.+)\ \([0-9]+\ apps?\)$ && -n "${GROUP_MEMBERS[${BASH_REMATCH[1]}]+set}" ]]; then
+        echo "${BASH_REMATCH[1]}"
+        return 0
+    fi
+    return 1
+}
+
 # Function to display unified menu
 show_unified_menu() {
     local -a menu_items=()
@@ -1084,10 +2602,12 @@ show_unified_menu() {
     local prev_filter=""
     local first_draw=true
     local need_full_clear=false
+    local jump_status=""  # "[k/N: action]" indicator after Ctrl+N / Ctrl+P
+    local -A collapsed_groups=() # Groups whose member apps are hidden (toggled with ←/→)
 
     # Scrolling and viewport variables
-    local terminal_height
-    terminal_height=$(tput lines 2>/dev/null || echo 24) # Default to 24 if tput fails
+    local terminal_height=0
+    local terminal_resized=false # Set by the SIGWINCH trap while waiting for a key
     
     local title_box_height=4 # 3 for box, 1 for blank line after
     local help_lines_height=3 # 2 for help, 1 for blank line after
@@ -1099,62 +2619,77 @@ show_unified_menu() {
 
     local static_header_actual_height
     local show_title_box=true
-    if [[ $terminal_height -lt $min_height_for_title_box ]]; then
-        show_title_box=false
-        static_header_actual_height=$help_lines_height # Only help lines
-    else
-        show_title_box=true
-        static_header_actual_height=$((title_box_height + help_lines_height)) # Title box + help lines
-    fi
-    
-    local dynamic_content_start_line=$((static_header_actual_height + 1))
-    
-    local menu_max_display_lines=$((terminal_height - static_header_actual_height - status_lines_height - scroll_indicator_lines - reserved_bottom_line))
-    if [[ $menu_max_display_lines -lt $min_menu_items_display ]]; then
-        # If not enough space even for min display, check if we can at least show min_menu_items_display
-        # by sacrificing the reserved bottom line.
-        local potential_max_lines_no_reserve=$((terminal_height - static_header_actual_height - status_lines_height - scroll_indicator_lines))
-        if [[ $potential_max_lines_no_reserve -ge $min_menu_items_display ]]; then
-             menu_max_display_lines=$potential_max_lines_no_reserve
-        elif [[ $potential_max_lines_no_reserve -lt 0 ]]; then # Not enough space at all
-            menu_max_display_lines=0
-        else
-            menu_max_display_lines=$potential_max_lines_no_reserve # Show what we can, even if < min_menu_items_display
-        fi
-    fi
-    if [[ $menu_max_display_lines -lt 0 ]]; then menu_max_display_lines=0; fi
-
+    local dynamic_content_start_line
+    local menu_max_display_lines
 
     local view_offset=0 # Starting index of the visible part of the filtered items
 
+    # Config hot-reload: the file is checked while waiting for a key
+    local config_signature
+    config_signature=$(config_file_signature "$CONFIG_FILE")
+    local reload_status=""       # "Config reloaded" (or the reload error) shown for a few seconds
+    local reload_status_until=0
+    
     # Build menu items
-    for app in "${APPS[@]}"; do
-        local actions="${APP_ACTION_LIST[$app]:-}"
-        if [[ -n "$actions" ]]; then
-            for action in $actions; do
-                menu_items+=("$app - $action")
-            done
-        fi
-        menu_items+=("$app - Show Details")
-    done
+    readarray -t menu_items < <(build_menu_items)
+    
+    # Selection left over from a previous run with the same config, restored with Ctrl+R
+    local -a session_items=()
+    readarray -t session_items < <(load_session_items)
     
     printf '\033[?25l' # Hide cursor
     trap 'printf "\033[?25h"' EXIT # Ensure cursor is shown on exit
     
     while true; do
+        # (Re)compute the layout on first draw and whenever the terminal height changes
+        local current_height
+        current_height=$(tput lines 2>/dev/null || echo 24) # Default to 24 if tput fails
+        if [[ "$current_height" != "$terminal_height" ]]; then
+            terminal_height=$current_height
+            if [[ $terminal_height -lt $min_height_for_title_box ]]; then
+                show_title_box=false
+                static_header_actual_height=$help_lines_height # Only help lines
+            else
+                show_title_box=true
+                static_header_actual_height=$((title_box_height + help_lines_height)) # Title box + help lines
+            fi
+
+            dynamic_content_start_line=$((static_header_actual_height + 1))
+
+            menu_max_display_lines=$((terminal_height - static_header_actual_height - status_lines_height - scroll_indicator_lines - reserved_bottom_line))
+            if [[ $menu_max_display_lines -lt $min_menu_items_display ]]; then
+                # If not enough space even for min display, check if we can at least show min_menu_items_display
+                # by sacrificing the reserved bottom line.
+                local potential_max_lines_no_reserve=$((terminal_height - static_header_actual_height - status_lines_height - scroll_indicator_lines))
+                if [[ $potential_max_lines_no_reserve -ge $min_menu_items_display ]]; then
+                     menu_max_display_lines=$potential_max_lines_no_reserve
+                elif [[ $potential_max_lines_no_reserve -lt 0 ]]; then # Not enough space at all
+                    menu_max_display_lines=0
+                else
+                    menu_max_display_lines=$potential_max_lines_no_reserve # Show what we can, even if < min_menu_items_display
+                fi
+            fi
+            if [[ $menu_max_display_lines -lt 0 ]]; then menu_max_display_lines=0; fi
+            need_full_clear=true
+        fi
+        if [[ "$terminal_resized" == "true" ]]; then
+            terminal_resized=false
+            need_full_clear=true
+        fi
+
         if [[ "$first_draw" == "true" ]] || [[ "$need_full_clear" == "true" ]]; then
             clear
             printf '\033[H' # Cursor to home
             
             # Print static header
             if [[ "$show_title_box" == "true" ]]; then
-                print_color "$BLUE" "╔══════════════════════════════════════════════════════════════════════════════════════╗"
-                print_color "$BLUE" "║          Shell-Bun by Fredrik Reveny (https://github.com/Chetic/shell-bun/)          ║"
-                print_color "$BLUE" "╚══════════════════════════════════════════════════════════════════════════════════════╝"
+                print_color "${THEME[header]}" "╔══════════════════════════════════════════════════════════════════════════════════════╗"
+                print_color "${THEME[header]}" "║          Shell-Bun by Fredrik Reveny (https://github.com/Chetic/shell-bun/)          ║"
+                print_color "${THEME[header]}" "╚══════════════════════════════════════════════════════════════════════════════════════╝"
                 echo
             fi
-            print_color "$CYAN" "Navigation: ↑/↓ arrows | PgUp/PgDn: page | Type: filter | Space: select | Enter: execute | ESC: quit"
-            print_color "$CYAN" "Shortcuts: '+' select visible | '-' deselect visible | Delete: clear filter | Enter: run current or selected"
+            print_color "${THEME[help]}" "Navigation: ↑/↓ arrows | PgUp/PgDn: page | Type: filter | Space: select | Enter: execute | ←/→: fold group | ESC: quit"
+            print_color "${THEME[help]}" "Shortcuts: '+' select visible | '-' deselect visible | Delete: clear filter | Ctrl+N/P: same action | Ctrl+O: open log dir | Ctrl+E: run selected in sequence"
             echo
 
             first_draw=false
@@ -1189,23 +2724,29 @@ show_unified_menu() {
         # Always print dynamic content from here
         # Display filter status and selection count (Dynamic Header)
         if [[ -n "$filter" ]]; then
-            print_color "$YELLOW" "Filter: $filter"
+            print_color "${THEME[filter]}" "Filter: $filter${jump_status:+   $jump_status}"
         else
-            print_color "$DIM" "Filter: (type to search)"
+            print_color "${THEME[dim]}" "Filter: (type to search)${jump_status:+   $jump_status}"
         fi
         
         local selected_count
         selected_count=$(selected_items_count)
         if [[ $selected_count -gt 0 ]]; then
-            print_color "$GREEN" "Selected: ${selected_count} items"
+            print_color "${THEME[selected]}" "Selected: ${selected_count} items${reload_status:+   $reload_status}"
         else
-            print_color "$DIM" "Selected: none"
+            print_color "${THEME[dim]}" "Selected: none${reload_status:+   $reload_status}"
         fi
 
-        # Filter menu items
+        # Filter menu items (collapsed groups hide their apps unless a filter is searching them)
         local -a filtered=()
         for item in "${menu_items[@]}"; do
-            if [[ -z "$filter" ]] || [[ "${item,,}" == *"${filter,,}"* ]]; then
+            if [[ -z "$filter" ]]; then
+                local item_group="${APP_GROUP[${item% - *}]:-}"
+                if [[ "$item" == *" - "* && -n "$item_group" && -n "${collapsed_groups[$item_group]:-}" ]]; then
+                    continue
+                fi
+                filtered+=("$item")
+            elif [[ "${item,,}" == *"${filter,,}"* ]]; then
                 filtered+=("$item")
             fi
         done
@@ -1236,7 +2777,7 @@ show_unified_menu() {
         
         # Display "items above" indicator
         if [[ $view_offset -gt 0 ]]; then
-            print_color "$DIM" "  ... $((view_offset)) more item(s) above ..."
+            print_color "${THEME[dim]}" "  ... $((view_offset)) more item(s) above ..."
         else
             if [[ $num_filtered -gt $menu_max_display_lines && $menu_max_display_lines -gt 0 ]]; then echo ""; fi # Keep spacing if scrollable
         fi
@@ -1255,29 +2796,43 @@ show_unified_menu() {
                 local is_currently_selected=false
                 local is_highlighted=false
                 local is_show_details=false
+                local header_group=""
                 
                 if [[ "$item" =~ "- Show Details"$ ]]; then is_show_details=true; fi
+                if [[ "$item" == "Group: "* ]] && header_group=$(menu_item_group "$item"); then
+                    # Group header: arrow shows whether its apps are expanded
+                    local group_marker="▼"
+                    if [[ -n "${collapsed_groups[$header_group]:-}" ]]; then group_marker="▶"; fi
+                    if [[ $i -eq $selected ]]; then
+                        print_color "${THEME[highlight]}" "► ${group_marker} ${item}"
+                    else
+                        print_color "${THEME[group]}" "  ${group_marker} ${item}"
+                    fi
+                    continue
+                fi
                 if is_selected "$item"; then suffix=" [✓]"; is_currently_selected=true; fi
                 if [[ $i -eq $selected ]]; then prefix="► "; is_highlighted=true; fi
+                # Indent the apps of a group below its header
+                if [[ -n "${APP_GROUP[${item% - *}]:-}" ]]; then prefix="$prefix  "; fi
                 
                 if [[ "$is_currently_selected" == "true" && "$is_highlighted" == "true" ]]; then
-                    print_color "$BOLD$GREEN" "${prefix}${item}${suffix}"
+                    print_color "$BOLD${THEME[selected]}" "${prefix}${item}${suffix}"
                 elif [[ "$is_currently_selected" == "true" ]]; then
-                    print_color "$GREEN" "${prefix}${item}${suffix}"
+                    print_color "${THEME[selected]}" "${prefix}${item}${suffix}"
                 elif [[ "$is_highlighted" == "true" && "$is_show_details" == "true" ]]; then
-                    print_color "$BOLD$PURPLE" "${prefix}${item}${suffix}"
+                    print_color "${THEME[details_highlight]}" "${prefix}${item}${suffix}"
                 elif [[ "$is_highlighted" == "true" ]]; then
-                    print_color "$CYAN" "${prefix}${item}${suffix}"
+                    print_color "${THEME[highlight]}" "${prefix}${item}${suffix}"
                 elif [[ "$is_show_details" == "true" ]]; then
-                    print_color "$YELLOW" "${prefix}${item}${suffix}"
+                    print_color "${THEME[details]}" "${prefix}${item}${suffix}"
                 else
-                    echo "  ${item}${suffix}"
+                    echo "${prefix}${item}${suffix}"
                 fi
             done
         fi
         
         if [[ $num_filtered -eq 0 && $menu_max_display_lines -gt 0 ]]; then
-            print_color "$RED" "No matches found"
+            print_color "${THEME[error]}" "No matches found"
         fi
 
         # Display "items below" indicator
@@ -1292,16 +2847,54 @@ show_unified_menu() {
 
         if [[ $num_filtered -gt 0 && $menu_max_display_lines -gt 0 && $((view_offset + items_actually_shown_in_viewport)) -lt $num_filtered ]]; then
             local items_below=$((num_filtered - (view_offset + items_actually_shown_in_viewport)))
-            print_color "$DIM" "  ... $((items_below)) more item(s) below ..."
+            print_color "${THEME[dim]}" "  ... $((items_below)) more item(s) below ..."
         else
             if [[ $num_filtered -gt $menu_max_display_lines && $menu_max_display_lines -gt 0 ]]; then echo ""; fi # Keep spacing if scrollable
         fi
         
+        # Offer the saved selection on the reserved bottom line (no newline, so the screen does not scroll)
+        if [[ ${#session_items[@]} -gt 0 ]]; then
+            printf '\033[%d;1H\033[2K' "$terminal_height"
+            printf '%b' "${THEME[filter]}Restore ${#session_items[@]} selected item(s) from your last session? Press Ctrl+R${NC}"
+        fi
+        
         # Key handling (omitted for brevity in this thought, but it's the same as before)
 
         # Read user input with enhanced key detection
+        # Wait for a key, polling so a terminal resize (SIGWINCH) triggers a redraw for the new size
         unset key
-        IFS= read -rsn1 key 2>/dev/null || continue
+        local read_status
+        trap 'terminal_resized=true' WINCH
+        local config_changed=false
+        while true; do
+            IFS= read -rsn1 -t 0.5 key 2>/dev/null
+            read_status=$?
+            if [[ $read_status -le 128 || "$terminal_resized" == "true" ]]; then break; fi
+            if [[ -n "$reload_status" && $SECONDS -ge $reload_status_until ]]; then break; fi
+            local current_signature
+            current_signature=$(config_file_signature "$CONFIG_FILE")
+            if [[ "$current_signature" != "$config_signature" ]]; then
+                config_signature="$current_signature"
+                config_changed=true
+                break
+            fi
+        done
+        trap - WINCH
+        if [[ -n "$reload_status" && $SECONDS -ge $reload_status_until ]]; then
+            reload_status=""
+        fi
+        if [[ "$config_changed" == "true" ]]; then
+            if reload_config; then
+                readarray -t menu_items < <(build_menu_items)
+                reload_status="[Config reloaded]"
+            else
+                reload_status="[Config reload failed: $RELOAD_ERROR]"
+            fi
+            reload_status_until=$((SECONDS + 3))
+            need_full_clear=true
+        fi
+        if [[ $read_status -ne 0 ]]; then continue; fi
+        jump_status=""
         
         # Advanced debugging for WSL key detection issues
         key_hex=$(printf '%02x' "'$key" 2>/dev/null || echo 'empty')
@@ -1368,6 +2961,34 @@ show_unified_menu() {
                     if [[ $selected -lt $((${#filtered[@]} - 1)) ]] && [[ ${#filtered[@]} -gt 0 ]]; then
                         ((selected++))
                     fi
+                elif [[ "$arrows" == "[D" || "$arrows" == "[C" ]]; then
+                    # Left/Right arrow - collapse/expand the highlighted group (Left on an app collapses its group)
+                    if [[ ${#filtered[@]} -gt 0 ]]; then
+                        local toggle_group=""
+                        if ! toggle_group=$(menu_item_group "${filtered[$selected]}"); then
+                            toggle_group=""
+                            if [[ "$arrows" == "[D" && "${filtered[$selected]}" == *" - "* ]]; then
+                                toggle_group="${APP_GROUP[${filtered[$selected]% - *}]:-}"
+                            fi
+                        fi
+                        if [[ -n "$toggle_group" ]]; then
+                            if [[ "$arrows" == "[D" ]]; then
+                                collapsed_groups["$toggle_group"]=1
+                            else
+                                unset 'collapsed_groups[$toggle_group]'
+                            fi
+                            # Keep the group header highlighted
+                            local header_item
+                            header_item=$(group_header_item "$toggle_group")
+                            for i in "${!filtered[@]}"; do
+                                if [[ "${filtered[$i]}" == "$header_item" ]]; then
+                                    selected=$i
+                                    break
+                                fi
+                            done
+                            need_full_clear=true
+                        fi
+                    fi
                 elif [[ "$arrows" == "[5" ]]; then
                     # Page Up - read the final ~ character
                     read -rsn1 -t 0.1 final_char 2>/dev/null
@@ -1417,7 +3038,16 @@ show_unified_menu() {
                 if [[ ${#filtered[@]} -gt 0 ]]; then
                     local selection="${filtered[$selected]}"
                     debug_log "Selected item: '$selection'"
-                    if [[ "$selection" =~ ^(.+)\ -\ Show\ Details$ ]]; then
+                    local toggle_group=""
+                    if toggle_group=$(menu_item_group "$selection"); then
+                        # Group header: toggle between collapsed and expanded
+                        if [[ -n "${collapsed_groups[$toggle_group]:-}" ]]; then
+                            unset 'collapsed_groups[$toggle_group]'
+                        else
+                            collapsed_groups["$toggle_group"]=1
+                        fi
+                        need_full_clear=true
+                    elif [[ "$selection" =~ ^(.+)\ -\ Show\ Details$ ]]; then
                         debug_log "Showing details for app"
                         local app="${BASH_REMATCH[1]}"
                         clear
@@ -1453,9 +3083,11 @@ show_unified_menu() {
                 if [[ ${#filtered[@]} -gt 0 ]]; then
                     local selection="${filtered[$selected]}"
                     debug_log "Current selection: '$selection'"
-                    if [[ ! "$selection" =~ -\ Show\ Details$ ]]; then
+                    if [[ ! "$selection" =~ -\ Show\ Details$ ]] && ! menu_item_group "$selection" >/dev/null; then
                         debug_log "Toggling selection for: '$selection'"
                         toggle_selection "$selection"
+                        session_items=()
+                        save_session
                         debug_log "After toggle, selected items: $(selected_items_count)"
                         need_full_clear=true
                     else
@@ -1471,7 +3103,16 @@ show_unified_menu() {
                 if [[ ${#filtered[@]} -gt 0 ]]; then
                     local selection="${filtered[$selected]}"
                     debug_log "Selected item: '$selection'"
-                    if [[ "$selection" =~ ^(.+)\ -\ Show\ Details$ ]]; then
+                    local toggle_group=""
+                    if toggle_group=$(menu_item_group "$selection"); then
+                        # Group header: toggle between collapsed and expanded
+                        if [[ -n "${collapsed_groups[$toggle_group]:-}" ]]; then
+                            unset 'collapsed_groups[$toggle_group]'
+                        else
+                            collapsed_groups["$toggle_group"]=1
+                        fi
+                        need_full_clear=true
+                    elif [[ "$selection" =~ ^(.+)\ -\ Show\ Details$ ]]; then
                         debug_log "Showing details for app"
                         local app="${BASH_REMATCH[1]}"
                         clear
@@ -1505,12 +3146,16 @@ show_unified_menu() {
             '+') # Plus - select all filtered items
                 debug_log "Plus key pressed - selecting all filtered items"
                 select_filtered "${filtered[@]}"
+                session_items=()
+                save_session
                 need_full_clear=true
                 action_taken=true
                 ;;
             '-') # Minus - deselect filtered items
                 debug_log "Minus key pressed - deselecting filtered items"
                 deselect_filtered "${filtered[@]}"
+                session_items=()
+                save_session
                 need_full_clear=true
                 action_taken=true
                 ;;
@@ -1534,6 +3179,77 @@ show_unified_menu() {
                 need_full_clear=true
                 action_taken=true
                 ;;
+            $'\x0e'|$'\x10') # Ctrl+N / Ctrl+P - jump to the next/previous action with the same name
+                if [[ ${#filtered[@]} -gt 0 && ! "${filtered[$selected]}" =~ -\ Show\ Details$ && "${filtered[$selected]}" =~ ^(.+)\ -\ (.+)$ ]]; then
+                    local jump_action="${BASH_REMATCH[2]}"
+                    local -a same_action_indices=()
+                    local position=0
+                    for i in "${!filtered[@]}"; do
+                        if [[ "${filtered[$i]}" =~ ^(.+)\ -\ (.+)$ && "${BASH_REMATCH[2]}" == "$jump_action" ]]; then
+                            if [[ $i -eq $selected ]]; then
+                                position=${#same_action_indices[@]}
+                            fi
+                            same_action_indices+=("$i")
+                        fi
+                    done
+                    
+                    local match_count=${#same_action_indices[@]}
+                    if [[ "$key" == $'\x0e' ]]; then
+                        position=$(((position + 1) % match_count))
+                    else
+                        position=$(((position - 1 + match_count) % match_count))
+                    fi
+                    selected=${same_action_indices[$position]}
+                    jump_status="[$((position + 1))/$match_count: $jump_action]"
+                    debug_log "Jumped to '${filtered[$selected]}' $jump_status"
+                fi
+                action_taken=true
+                ;;
+            $'\x05') # Ctrl+E - run the selected items one after another
+                debug_log "Ctrl+E pressed - executing selected items sequentially"
+                local selected_count
+                selected_count=$(selected_items_count)
+                if [[ $selected_count -gt 0 ]]; then
+                    execute_parallel sequential
+                    need_full_clear=true
+                elif [[ ${#filtered[@]} -gt 0 ]]; then
+                    # Nothing selected - behave like Enter on an action
+                    local selection="${filtered[$selected]}"
+                    if [[ ! "$selection" =~ -\ Show\ Details$ && "$selection" =~ ^(.+)\ -\ (.+)$ ]]; then
+                        execute_single "${BASH_REMATCH[1]}" "${BASH_REMATCH[2]}"
+                        need_full_clear=true
+                    fi
+                fi
+                action_taken=true
+                ;;
+            $'\x12') # Ctrl+R - restore the selection saved by the last session
+                if [[ ${#session_items[@]} -gt 0 ]]; then
+                    debug_log "Restoring ${#session_items[@]} item(s) from $SESSION_FILE"
+                    local session_item
+                    for session_item in "${session_items[@]}"; do
+                        if ! is_selected "$session_item"; then
+                            SELECTED_ITEMS+=("$session_item")
+                        fi
+                    done
+                    session_items=()
+                    save_session
+                    need_full_clear=true
+                fi
+                action_taken=true
+                ;;
+            $'\x0f') # Ctrl+O - open the highlighted app's log directory
+                debug_log "Ctrl+O pressed - opening log directory"
+                if [[ ${#filtered[@]} -gt 0 ]] && ! menu_item_group "${filtered[$selected]}" >/dev/null; then
+                    local selection="${filtered[$selected]}"
+                    local app="${selection%% - *}"
+                    if ! open_log_directory "$app"; then
+                        echo "Press Enter to continue..."
+                        read
+                    fi
+                    need_full_clear=true
+                fi
+                action_taken=true
+                ;;
         esac
         
         # If no action was taken by special keys, handle as filter input
@@ -1552,6 +3268,110 @@ show_unified_menu() {
     done
 }
 
+# Function to print CI results as an aligned table
+# Each argument is a tab-separated record: app, action, status, duration in ms
+print_ci_results_table() {
+    local app_width=20
+    local action_width=20
+    local status_width=9
+    local duration_width=8
+    
+    # Scale the name columns with the terminal width (80 columns is the reference)
+    if [[ -t 1 ]]; then
+        local columns
+        columns=$(tput cols 2>/dev/null || echo 80)
+        if [[ "$columns" =~ ^[0-9]+$ && $columns -gt 0 ]]; then
+            app_width=$((20 * columns / 80))
+            action_width=$((20 * columns / 80))
+            if [[ $app_width -lt 10 ]]; then app_width=10; fi
+            if [[ $action_width -lt 10 ]]; then action_width=10; fi
+        fi
+    fi
+    
+    local separator
+    separator=$(printf '%*s' $((app_width + action_width + status_width + duration_width + 6)) '' | tr ' ' '-')
+    
+    printf "%-${app_width}.${app_width}s  %-${action_width}.${action_width}s  %-${status_width}s  %${duration_width}s\n" \
+        "App" "Action" "Status" "Duration"
+    echo "$separator"
+    
+    local record
+    for record in "$@"; do
+        local app action status duration_ms
+        IFS=$'\t' read -r app action status duration_ms _ <<< "$record"
+        
+        local status_color="$GREEN"
+        if [[ "$status" != "SUCCESS" ]]; then
+            status_color="$RED"
+        fi
+        
+        printf "%-${app_width}.${app_width}s  %-${action_width}.${action_width}s  ${status_color}%-${status_width}s${NC}  %${duration_width}s\n" \
+            "$app" "$action" "$status" "$(format_duration "$duration_ms")"
+    done
+    echo "$separator"
+}
+
+# Function to escape a string for use inside a JSON string literal
+json_escape() {
+    local value="$1"
+    value="${value//\\/\\\\}"
+    value="${value//\"/\\\"}"
+    value="${value//$'\n'/\\n}"
+    value="${value//$'\r'/\\r}"
+    value="${value//$'\t'/\\t}"
+    printf '%s' "$value"
+}
+
+# Function to print every app and its actions as a JSON document (--list --json)
+print_action_list_json() {
+    local app action
+    local app_separator=""
+    
+    echo '{'
+    echo '  "apps": ['
+    for app in "${APPS[@]}"; do
+        local actions_json=""
+        while IFS= read -r action; do
+            actions_json="${actions_json:+$actions_json, }\"$(json_escape "$action")\""
+        done < <(get_actions "$app")
+        printf '%s    {"app": "%s", "actions": [%s]}' "$app_separator" "$(json_escape "$app")" "$actions_json"
+        app_separator=$',\n'
+    done
+    if [[ -n "$app_separator" ]]; then
+        echo
+    fi
+    echo '  ]'
+    echo '}'
+}
+
+# Function to print CI results as a JSON document
+# Each record is "app<TAB>action<TAB>status<TAB>duration_ms<TAB>exit_code<TAB>log_file"
+print_ci_results_json() {
+    local record
+    local separator=""
+    
+    echo '{'
+    echo '  "results": ['
+    for record in "$@"; do
+        local app action status duration_ms exit_code log_file
+        IFS=$'\t' read -r app action status duration_ms exit_code log_file <<< "$record"
+        
+        local success="false"
+        if [[ "$status" == "SUCCESS" ]]; then
+            success="true"
+        fi
+        
+        printf '%s    {"app": "%s", "action": "%s", "success": %s, "exit_code": %d, "log_file": "%s"}' \
+            "$separator" "$(json_escape "$app")" "$(json_escape "$action")" "$success" "$exit_code" "$(json_escape "$log_file")"
+        separator=$',\n'
+    done
+    if [[ -n "$separator" ]]; then
+        echo
+    fi
+    echo '  ]'
+    echo '}'
+}
+
 # Function to execute commands in CI mode (non-interactive)
 execute_ci_mode() {
     local app_pattern="$1"
@@ -1570,18 +3390,23 @@ execute_ci_mode() {
         echo "  - Wildcards: *Web*, API*"
         echo "  - Substrings: web, api"
         echo "  - Multiple: MyWebApp,API*,mobile"
-        exit 1
+        exit $CI_EXIT_NO_MATCH
     fi
     
     local -a matched_apps
     readarray -t matched_apps <<< "$matched_apps_output"
     
-    # Prepare completely parallel execution (all actions run in parallel)
+    # Collect the matched actions first, then run them in parallel (capped by --parallel/parallel=)
     local -a pids=()
     local -a command_descriptions=()
+    local -a command_apps=()
+    local -a command_actions=()
     local found_any_action=false
     
-    # Start all matched commands in parallel
+    # Each background job records its own duration here
+    local timing_dir
+    timing_dir=$(mktemp -d 2>/dev/null || mktemp -d -t shell-bun)
+    
     for app in "${matched_apps[@]}"; do
         # Skip empty entries
         [[ -z "$app" ]] && continue
@@ -1592,8 +3417,9 @@ execute_ci_mode() {
         
         if [[ -z "$matched_actions_output" ]]; then
             echo "Warning: No actions found for '$app' matching pattern '$action_pattern'"
-            local actions="${APP_ACTION_LIST[$app]:-}"
-            echo "Available actions for $app: $actions"
+            local -a available_actions=()
+            readarray -t available_actions < <(get_actions "$app")
+            echo "Available actions for $app: ${available_actions[*]}"
             continue
         fi
         
@@ -1602,31 +3428,32 @@ execute_ci_mode() {
         local -a matched_actions
         readarray -t matched_actions <<< "$matched_actions_output"
         
-        # Start each action in parallel
         for action in "${matched_actions[@]}"; do
             # Skip empty entries
             [[ -z "$action" ]] && continue
-            
-            # Start each action as a separate background process
-            execute_command "$app" "$action" "false" "" &
-            pids+=($!)
             command_descriptions+=("$app - $action")
+            command_apps+=("$app")
+            command_actions+=("$action")
         done
     done
     
     # Check if any actions were found
-    if [[ "$found_any_action" == "false" || ${#pids[@]} -eq 0 ]]; then
+    if [[ "$found_any_action" == "false" || ${#command_apps[@]} -eq 0 ]]; then
+        rm -rf "$timing_dir"
         echo ""
         echo "Error: No actions found matching pattern '$action_pattern'"
-        exit 1
+        exit $CI_EXIT_NO_MATCH
     fi
     
     # Determine if this is a single action execution
     local is_single_action=false
-    if [[ ${#pids[@]} -eq 1 ]]; then
+    if [[ ${#command_apps[@]} -eq 1 ]]; then
         is_single_action=true
     fi
     
+    local parallel_limit
+    parallel_limit=$(resolve_parallel_limit)
+    
     # For multiple actions, show verbose header
     if [[ "$is_single_action" == "false" ]]; then
         echo "Shell-Bun CI Mode: Fuzzy Pattern Execution (Parallel)"
@@ -1636,26 +3463,96 @@ execute_ci_mode() {
         echo "Config: $CONFIG_FILE"
         echo "========================================"
         echo ""
-        echo "Running ${#pids[@]} actions in parallel..."
+        if [[ $parallel_limit -gt 0 && $parallel_limit -lt ${#command_apps[@]} ]]; then
+            echo "Running ${#command_apps[@]} actions in parallel (at most $parallel_limit at a time)..."
+        else
+            echo "Running ${#command_apps[@]} actions in parallel..."
+        fi
         echo "========================================"
     fi
     
+    # Start each action as a separate background process
+    local peak_concurrency=0
+    local job_index
+    for job_index in "${!command_apps[@]}"; do
+        wait_for_job_slot "$parallel_limit" "${pids[@]}"
+        local app="${command_apps[$job_index]}"
+        local action="${command_actions[$job_index]}"
+        (
+            local start_ms
+            start_ms=$(current_time_ms)
+            execute_command "$app" "$action" "false" "job_log_file"
+            local job_exit_code=$?
+            echo $(($(current_time_ms) - start_ms)) > "$timing_dir/$job_index"
+            echo "${job_log_file:-}" > "$timing_dir/$job_index.log"
+            exit $job_exit_code
+        ) &
+        pids+=($!)
+        local running_jobs
+        running_jobs=$(count_running_jobs "${pids[@]}")
+        if [[ $running_jobs -gt $peak_concurrency ]]; then
+            peak_concurrency=$running_jobs
+        fi
+    done
+    
     # Wait for all background processes and collect results
     local total_success=0
     local total_failure=0
+    local total_timeout=0
     local -a failed_commands=()
+    local -a result_records=()
     
     for i in "${!pids[@]}"; do
         local pid="${pids[$i]}"
         local cmd_description="${command_descriptions[$i]}"
+        local result_status="SUCCESS"
+        local job_exit_code=0
         
         if wait "$pid"; then
             ((total_success++))
         else
+            job_exit_code=$?
             ((total_failure++))
             failed_commands+=("$cmd_description")
+            result_status="FAILED"
+            if [[ $job_exit_code -eq $TIMEOUT_EXIT_CODE ]]; then
+                result_status="TIMEOUT"
+                ((total_timeout++))
+            fi
+        fi
+        
+        local duration_ms=0
+        if [[ -f "$timing_dir/$i" ]]; then
+            duration_ms=$(<"$timing_dir/$i")
+        fi
+        local job_log_file=""
+        if [[ -f "$timing_dir/$i.log" ]]; then
+            job_log_file=$(<"$timing_dir/$i.log")
         fi
+        result_records+=("${command_apps[$i]}"$'\t'"${command_actions[$i]}"$'\t'"$result_status"$'\t'"$duration_ms"$'\t'"$job_exit_code"$'\t'"$job_log_file")
     done
+    rm -rf "$timing_dir"
+    
+    # Exit code tells CI scripts what went wrong: a timeout wins, then all vs. some failed
+    local ci_exit_code=0
+    if [[ $total_timeout -gt 0 ]]; then
+        ci_exit_code=$TIMEOUT_EXIT_CODE
+    elif [[ $total_failure -gt 0 && $total_success -eq 0 ]]; then
+        ci_exit_code=$CI_EXIT_ALL_FAILED
+    elif [[ $total_failure -gt 0 ]]; then
+        ci_exit_code=$CI_EXIT_PARTIAL_FAILURE
+    fi
+    
+    if [[ "$OUTPUT_FORMAT" == "json" ]]; then
+        print_ci_results_json "${result_records[@]}" >&3
+        exit $ci_exit_code
+    fi
+    
+    if [[ "$OUTPUT_FORMAT" == "table" ]]; then
+        echo ""
+        print_ci_results_table "${result_records[@]}"
+        exit $ci_exit_code
+    fi
     
     # Only show summary if more than one action was executed
     if [[ "$is_single_action" == "false" ]]; then
@@ -1664,25 +3561,67 @@ execute_ci_mode() {
         echo "CI Execution Summary (Parallel):"
         echo "Commands executed: ${#pids[@]}"
         echo "✅ Successful operations: $total_success"
+        if [[ $parallel_limit -gt 0 ]]; then
+            echo "⚡ Peak concurrency: $peak_concurrency (limit $parallel_limit)"
+        else
+            echo "⚡ Peak concurrency: $peak_concurrency"
+        fi
         if [[ $total_failure -gt 0 ]]; then
             echo "❌ Failed operations: $total_failure"
             echo "Failed commands:"
             for failed_cmd in "${failed_commands[@]}"; do
                 echo "  - $failed_cmd"
             done
-            exit 1
         else
             echo "🎉 All operations completed successfully"
-            exit 0
         fi
-    else
-        # Single action: just exit with appropriate code
-        if [[ $total_failure -gt 0 ]]; then
-            exit 1
+    fi
+    exit $ci_exit_code
+}
+
+# Function to split a comma-separated pattern list, one trimmed pattern per line
+split_match_patterns() {
+    local pattern="$1"
+    local -a parts=()
+    local pending=""
+    local part
+    
+    IFS=',' read -ra parts <<< "$pattern"
+    for part in "${parts[@]}"; do
+        # Trim whitespace
+        part=$(echo "$part" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+        
+        # A /regex/ may itself contain commas: rejoin pieces until the closing slash
+        if [[ -n "$pending" ]]; then
+            pending+=",$part"
+            if [[ "$pending" =~ ^/.+/$ ]]; then
+                echo "$pending"
+                pending=""
+            fi
+        elif [[ "$part" == /* && ! "$part" =~ ^/.+/$ ]]; then
+            pending="$part"
         else
-            exit 0
+            echo "$part"
+        fi
+    done
+    if [[ -n "$pending" ]]; then
+        echo "$pending"
+    fi
+}
+
+# Function to report a /regex/ pattern that is not a valid extended regular expression
+check_regex_pattern() {
+    local pat="$1"
+    
+    if [[ "$pat" =~ ^/(.+)/$ ]]; then
+        local regex="${BASH_REMATCH[1]}"
+        [[ "" =~ $regex ]]
+        if [[ $? -eq 2 ]]; then
+            echo "Error: Invalid regular expression in pattern '$pat'" >&2
+            return 1
         fi
     fi
+    return 0
 }
 
 # Function to match applications using fuzzy patterns
@@ -1691,11 +3630,28 @@ match_apps_fuzzy() {
     local -a matched_apps=()
     
     # Split comma-separated patterns
-    IFS=',' read -ra patterns <<< "$pattern"
+    local -a patterns=()
+    readarray -t patterns < <(split_match_patterns "$pattern")
     
     for pat in "${patterns[@]}"; do
-        # Trim whitespace
-        pat=$(echo "$pat" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+        if ! check_regex_pattern "$pat"; then
+            continue
+        fi
+        
+        # A group name expands to its member apps
+        if [[ -n "${GROUP_MEMBERS[$pat]+set}" ]]; then
+            local member
+            while IFS= read -r member; do
+                local member_matched=false
+                for matched in "${matched_apps[@]}"; do
+                    [[ "$matched" == "$member" ]] && member_matched=true
+                done
+                if [[ "$member_matched" == "false" ]]; then
+                    matched_apps+=("$member")
+                fi
+            done < <(get_group_members "$pat")
+            continue
+        fi
         
         for app in "${APPS[@]}"; do
             # Check if already matched
@@ -1711,7 +3667,12 @@ match_apps_fuzzy() {
             
             if [[ "$already_matched" == "false" ]]; then
                 # Support different matching patterns
-                if [[ "$pat" == "$app" ]]; then
+                if [[ "$pat" =~ ^/(.+)/$ ]]; then
+                    # Regular expression match (/regex/)
+                    if [[ "$app" =~ ${BASH_REMATCH[1]} ]]; then
+                        matched_apps+=("$app")
+                    fi
+                elif [[ "$pat" == "$app" ]]; then
                     # Exact match
                     matched_apps+=("$app")
                 elif [[ "$pat" == *"*"* ]]; then
@@ -1738,10 +3699,7 @@ match_actions_fuzzy() {
     local available_actions=()
 
     # Get available actions for this app from the generic action list
-    local actions="${APP_ACTION_LIST[$app]:-}"
-    if [[ -n "$actions" ]]; then
-        read -r -a available_actions <<< "$actions"
-    fi
+    readarray -t available_actions < <(get_actions "$app")
 
     if [[ "$pattern" == "all" ]]; then
         # Return all available actions for "all"
@@ -1749,11 +3707,12 @@ match_actions_fuzzy() {
     else
         # Split comma-separated patterns
         local patterns=()
-        IFS=',' read -r -a patterns <<< "$pattern"
+        readarray -t patterns < <(split_match_patterns "$pattern")
 
         for pat in "${patterns[@]}"; do
-            # Trim whitespace
-            pat=$(echo "$pat" | sed 's/^[[:space:]]*//;s/[[:space:]]*$//')
+            if ! check_regex_pattern "$pat"; then
+                continue
+            fi
 
             for action in "${available_actions[@]}"; do
                 # Check if already matched
@@ -1769,7 +3728,12 @@ match_actions_fuzzy() {
 
                 if [[ "$already_matched" == "false" ]]; then
                     # Support different matching patterns
-                    if [[ "$pat" == "$action" ]]; then
+                    if [[ "$pat" =~ ^/(.+)/$ ]]; then
+                        # Regular expression match (/regex/)
+                        if [[ "$action" =~ ${BASH_REMATCH[1]} ]]; then
+                            matched_actions+=("$action")
+                        fi
+                    elif [[ "$pat" == "$action" ]]; then
                         # Exact match
                         matched_actions+=("$action")
                     elif [[ "$pat" == *"*"* ]]; then
@@ -1793,9 +3757,30 @@ match_actions_fuzzy() {
 
 # Main function
 main() {
+    # JSON results (and --list output) own stdout; everything else goes to stderr
+    if [[ $LIST_MODE -eq 1 ]] || [[ $CI_MODE -eq 1 && "$OUTPUT_FORMAT" == "json" ]]; then
+        exec 3>&1 1>&2
+    fi
+
+    # Handle --history (no configuration needed)
+    if [[ -n "$HISTORY_LIMIT" ]]; then
+        print_history "$HISTORY_LIMIT"
+        exit 0
+    fi
+
     # Parse the configuration file first
     print_color "$BLUE" "Loading configuration from: $CONFIG_FILE"
     parse_config
+    
+    # Handle --list (print every app and action, no menu or CI run)
+    if [[ $LIST_MODE -eq 1 ]]; then
+        if [[ "$OUTPUT_FORMAT" == "json" ]]; then
+            print_action_list_json >&3
+        else
+            all_action_pairs >&3
+        fi
+        exit 0
+    fi
 
     if [[ -n "$CONTAINER_COMMAND" ]]; then
         if [[ $CLI_CONTAINER_OVERRIDE -eq 1 ]]; then
@@ -1803,6 +3788,9 @@ main() {
         else
             print_color "$PURPLE" "Container mode enabled using: $CONTAINER_COMMAND"
         fi
+        if container_runtime_of "$CONTAINER_COMMAND" >/dev/null && ! container_runtime_is_known "$CONTAINER_COMMAND"; then
+            print_color "$YELLOW" "Warning: '$(container_runtime_of "$CONTAINER_COMMAND")' is not a known container runtime ($KNOWN_RUNTIMES); add it to known_runtimes to silence this warning"
+        fi
     elif [[ $CLI_CONTAINER_OVERRIDE -eq 1 ]]; then
         if [[ -n "$CONFIG_CONTAINER_COMMAND" ]]; then
             print_color "$YELLOW" "Container command overridden via --container (original: $CONFIG_CONTAINER_COMMAND)"
@@ -1810,6 +3798,13 @@ main() {
             print_color "$YELLOW" "Container command overridden via --container"
         fi
     fi
+    local app_name
+    for app_name in "${APPS[@]}"; do
+        local app_container="${APP_CONTAINER[$app_name]:-}"
+        if [[ -n "$app_container" ]] && container_runtime_of "$app_container" >/dev/null && ! container_runtime_is_known "$app_container"; then
+            print_color "$YELLOW" "Warning: '$(container_runtime_of "$app_container")' (container for $app_name) is not a known container runtime ($KNOWN_RUNTIMES); add it to known_runtimes to silence this warning"
+        fi
+    done
 
     # Handle CI mode (non-interactive)
     if [[ $CI_MODE -eq 1 ]]; then
@@ -1839,7 +3834,7 @@ main() {
         exit 1
     fi
     
-    print_color "$GREEN" "Found ${#APPS[@]} applications"
+    print_color "$GREEN" "Found $(app_count) applications with $(action_count) actions"
     if [[ ${#APPS[@]} -gt 0 ]]; then
         echo "Applications: ${APPS[*]}"
     fi
@@ -1848,5 +3843,10 @@ main() {
     show_unified_menu
 }
 
-# Run main function
-main "$@" 
+# Run main function when executed directly. When sourced, only the functions and
+# globals are defined so other scripts can embed Shell-Bun (see "Embedding" in README.md)
+if [[ -z "${BASH_SOURCE[0]:-}" || "${BASH_SOURCE[0]}" == "$0" ]]; then
+    set -uo pipefail
+    parse_arguments "$@"
+    main "$@"
+fi 
@@ -66,19 +66,19 @@ setup() {
 
 @test "CI mode: Error on non-existent app" {
     run bash "$SHELL_BUN" --ci NonExistentApp build "$TEST_FIXTURES/basic.cfg"
-    [ "$status" -eq 1 ]
+    [ "$status" -eq 2 ]
     [[ "$output" =~ "No applications found matching pattern" ]]
 }
 
 @test "CI mode: Error on non-existent action" {
     run bash "$SHELL_BUN" --ci TestApp1 nonexistent "$TEST_FIXTURES/basic.cfg"
-    [ "$status" -eq 1 ]
+    [ "$status" -eq 2 ]
     [[ "$output" =~ "No actions found" ]]
 }
 
 @test "CI mode: Handle command failure" {
     run bash "$SHELL_BUN" --ci FailApp fail_command "$TEST_FIXTURES/error.cfg"
-    [ "$status" -eq 1 ]
+    [ "$status" -eq 4 ]
     # Single action failure should not show summary
     [[ ! "$output" =~ "Failed operations" ]]
     [[ ! "$output" =~ "CI Execution Summary" ]]
@@ -105,3 +105,89 @@ setup() {
     [[ "$output" =~ "Action(s) required" ]]
 }
 
+
+@test "CI mode: --output table prints aligned results" {
+    run bash "$SHELL_BUN" --ci TestApp1 build,test --output table "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ App\ +Action\ +Status\ +Duration ]]
+    [[ "$output" =~ TestApp1\ +build\ + ]]
+    [[ "$output" =~ "SUCCESS" ]]
+    # The table replaces the paragraph summary
+    [[ ! "$output" =~ "CI Execution Summary" ]]
+}
+
+@test "CI mode: --output table reports failures and exits non-zero" {
+    run bash "$SHELL_BUN" --ci FailApp all --output=table "$TEST_FIXTURES/error.cfg"
+    [ "$status" -eq 3 ]
+    [[ "$output" =~ fail_command\ +.*FAILED ]]
+    [[ "$output" =~ success_command\ +.*SUCCESS ]]
+}
+
+@test "CI mode: --json prints only a JSON document on stdout" {
+    cat > "$BATS_TEST_TMPDIR/json.cfg" << EOF2
+log_dir=$BATS_TEST_TMPDIR/logs
+
+[JsonApp]
+build=echo "Building JsonApp"
+fail=exit 3
+EOF2
+
+    run bash -c "bash '$SHELL_BUN' --ci JsonApp build,fail --json '$BATS_TEST_TMPDIR/json.cfg' 2>/dev/null"
+    [ "$status" -eq 3 ]
+    [[ "${lines[0]}" == "{" ]]
+    [[ "$output" =~ \"app\":\ \"JsonApp\",\ \"action\":\ \"build\",\ \"success\":\ true,\ \"exit_code\":\ 0 ]]
+    [[ "$output" =~ \"action\":\ \"fail\",\ \"success\":\ false,\ \"exit_code\":\ 3 ]]
+    [[ "$output" =~ \"log_file\":\ \"$BATS_TEST_TMPDIR/logs/ ]]
+    # Command output and status messages go to stderr
+    [[ ! "$output" =~ "Building JsonApp" ]]
+    if command -v python3 >/dev/null 2>&1; then
+        echo "$output" | python3 -m json.tool > /dev/null
+    fi
+}
+
+@test "CI mode: --json log files hold the command output" {
+    cat > "$BATS_TEST_TMPDIR/json.cfg" << EOF2
+log_dir=$BATS_TEST_TMPDIR/logs
+
+[JsonApp]
+build=echo "Building JsonApp"
+EOF2
+
+    run bash "$SHELL_BUN" --ci JsonApp build --output=json "$BATS_TEST_TMPDIR/json.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building JsonApp" ]]
+    grep -q "Building JsonApp" "$BATS_TEST_TMPDIR"/logs/*_JsonApp_build.log
+}
+
+@test "CI mode: Exit code 4 when every action fails" {
+    printf '[FailApp]\nfirst=exit 1\nsecond=exit 2\n' > "$BATS_TEST_TMPDIR/allfail.cfg"
+    run bash "$SHELL_BUN" --ci FailApp all "$BATS_TEST_TMPDIR/allfail.cfg"
+    [ "$status" -eq 4 ]
+    [[ "$output" =~ "Failed operations: 2" ]]
+}
+
+@test "CI mode: --parallel caps concurrently running actions" {
+    printf '[Par]\na=sleep 0.3\nb=sleep 0.3\nc=sleep 0.3\nd=sleep 0.3\n' > "$BATS_TEST_TMPDIR/parallel.cfg"
+    run bash "$SHELL_BUN" --ci Par all --parallel 2 "$BATS_TEST_TMPDIR/parallel.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Running 4 actions in parallel (at most 2 at a time)..." ]]
+    [[ "$output" =~ "Peak concurrency: 2 (limit 2)" ]]
+}
+
+@test "CI mode: parallel config key is overridden by --parallel" {
+    printf 'parallel=1\n[Par]\na=sleep 0.2\nb=sleep 0.2\nc=sleep 0.2\n' > "$BATS_TEST_TMPDIR/parallel.cfg"
+    run bash "$SHELL_BUN" --ci Par all "$BATS_TEST_TMPDIR/parallel.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Peak concurrency: 1 (limit 1)" ]]
+
+    run bash "$SHELL_BUN" --ci Par all --parallel=0 "$BATS_TEST_TMPDIR/parallel.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Peak concurrency: 3" ]]
+    [[ ! "$output" =~ "limit" ]]
+}
+
+@test "CI mode: Error on invalid --parallel value" {
+    run bash "$SHELL_BUN" --ci TestApp1 build --parallel many "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Invalid --parallel value 'many'" ]]
+}
@@ -54,3 +54,45 @@ setup() {
     [ "$status" -eq 0 ]
 }
 
+
+@test "Unknown --output format is rejected" {
+    run bash "$SHELL_BUN" --output xml --ci TestApp1 build tests/fixtures/basic.cfg
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Unknown output format" ]]
+}
+
+@test "List flag prints app and action pairs on stdout" {
+    run bash -c "bash '$SHELL_BUN' --list '$SCRIPT_DIR/tests/fixtures/basic.cfg' 2>/dev/null"
+    [ "$status" -eq 0 ]
+    [ "${lines[0]}" = $'TestApp1\tbuild' ]
+    [ "${lines[4]}" = $'TestApp2\tdeploy' ]
+    [ "${#lines[@]}" -eq 5 ]
+}
+
+@test "List flag with --json prints the apps as JSON" {
+    run bash -c "bash '$SHELL_BUN' --list --json '$SCRIPT_DIR/tests/fixtures/basic.cfg' 2>/dev/null"
+    [ "$status" -eq 0 ]
+    [ "${lines[0]}" = "{" ]
+    [[ "$output" =~ '{"app": "TestApp1", "actions": ["build", "test", "clean"]},' ]]
+    [[ "$output" =~ '{"app": "TestApp2", "actions": ["build", "deploy"]}' ]]
+}
+
+@test "Executions are appended to the history file and shown by --history" {
+    export XDG_DATA_HOME="$BATS_TEST_TMPDIR/data"
+    run bash "$SHELL_BUN" --ci TestApp1 build,test "$SCRIPT_DIR/tests/fixtures/basic.cfg"
+    [ "$status" -eq 0 ]
+    [ "$(wc -l < "$XDG_DATA_HOME/shell-bun/history.jsonl")" -eq 2 ]
+    grep -q '"app": "TestApp1", "action": "build", "exit_code": 0, "duration_ms": ' "$XDG_DATA_HOME/shell-bun/history.jsonl"
+
+    run bash "$SHELL_BUN" --history 1
+    [ "$status" -eq 0 ]
+    [ "${#lines[@]}" -eq 1 ]
+    [[ "$output" =~ "✅ TestApp1 - " ]]
+}
+
+@test "History flag without any recorded executions" {
+    export XDG_DATA_HOME="$BATS_TEST_TMPDIR/empty"
+    run bash "$SHELL_BUN" --history
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "No executions recorded yet" ]]
+}
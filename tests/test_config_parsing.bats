@@ -45,3 +45,255 @@ setup() {
     [[ "$output" =~ "Container mode enabled" ]]
 }
 
+
+@test "Error on configuration path that is a directory" {
+    run bash "$SHELL_BUN" --ci TestApp1 build "$TEST_FIXTURES"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "is a directory" ]]
+}
+
+@test "Error on empty configuration file" {
+    : > "$BATS_TEST_TMPDIR/empty.cfg"
+    run bash "$SHELL_BUN" --ci TestApp1 build "$BATS_TEST_TMPDIR/empty.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "is empty" ]]
+}
+
+@test "Error on corrupted configuration file with NUL bytes" {
+    printf '[TestApp]\nbuild=ec\0ho\n' > "$BATS_TEST_TMPDIR/corrupt.cfg"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/corrupt.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "appears to be corrupted (NUL byte at byte offset 18)" ]]
+}
+
+@test "Warn about non-UTF-8 characters but still parse" {
+    if ! command -v iconv >/dev/null 2>&1; then
+        skip "iconv not available"
+    fi
+    printf '# caf\xe9\n[TestApp]\nbuild=echo ok\n' > "$BATS_TEST_TMPDIR/latin1.cfg"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/latin1.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "non-UTF-8 characters at byte offset 5" ]]
+    [[ "$output" =~ "ok" ]]
+}
+
+@test "Error lists every duplicate action name" {
+    printf '[AppA]\nbuild=echo one\ntest=echo t\nbuild=echo two\n[AppB]\nlint=echo l\nlint=echo l2\n' > "$BATS_TEST_TMPDIR/dups.cfg"
+    run bash "$SHELL_BUN" --ci AppA build "$BATS_TEST_TMPDIR/dups.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Duplicate action names" ]]
+    [[ "$output" =~ "AppA:build" ]]
+    [[ "$output" =~ "AppB:lint" ]]
+    [[ ! "$output" =~ "AppA:test" ]]
+}
+
+@test "Action whose name is a substring of another action is kept" {
+    printf '[TestApp]\nbuild_all=echo all\nbuild=echo single\n' > "$BATS_TEST_TMPDIR/substr.cfg"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/substr.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "single" ]]
+}
+
+@test "Parse TOML configuration file" {
+    run bash "$SHELL_BUN" --ci "TestApp*" build,test "$TEST_FIXTURES/basic.toml"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building TestApp1" ]]
+    [[ "$output" =~ "Testing TestApp1" ]]
+    [[ "$output" =~ "Building TestApp2" ]]
+}
+
+@test "TOML basic strings decode escapes" {
+    printf '[TestApp]\nbuild = "echo \\"tab:\\t\\" \\u0041"  # comment\n' > "$BATS_TEST_TMPDIR/escapes.toml"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/escapes.toml"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "tab:"$'\t'" A" ]]
+}
+
+@test "Error on unsupported TOML value with its line number" {
+    printf '[TestApp]\nbuild = "echo ok"\ntest = ["echo", "no"]\n' > "$BATS_TEST_TMPDIR/array.toml"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/array.toml"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Unsupported TOML value for 'test' on line 3" ]]
+}
+
+@test "Per-app env variables are passed to that app's commands only" {
+    printf '[EnvApp]\nenv.GREETING = hello world\nenv.TARGET=arm64\nbuild=echo "env:$GREETING:$TARGET"\n[PlainApp]\nbuild=echo "plain:${GREETING:-unset}"\n' > "$BATS_TEST_TMPDIR/env.cfg"
+    run bash "$SHELL_BUN" --ci "EnvApp,PlainApp" build "$BATS_TEST_TMPDIR/env.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "env:hello world:arm64" ]]
+    [[ "$output" =~ "plain:unset" ]]
+}
+
+@test "Error on invalid env variable name" {
+    printf '[TestApp]\nenv.BAD-NAME=x\nbuild=echo ok\n' > "$BATS_TEST_TMPDIR/badenv.cfg"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/badenv.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Invalid environment variable name 'BAD-NAME' on line 2" ]]
+}
+
+@test "Per-app env variables are exported inside the container" {
+    # env -i simulates a container runtime that does not forward the host environment
+    printf 'container=env -i PATH=/usr/bin:/bin\nknown_runtimes=env\n[EnvApp]\nenv.TARGET=arm64\nbuild=echo "target:$TARGET"\n' > "$BATS_TEST_TMPDIR/envcontainer.cfg"
+    run bash "$SHELL_BUN" --ci EnvApp build "$BATS_TEST_TMPDIR/envcontainer.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "target:arm64" ]]
+}
+
+@test "Reloading the config picks up edits and drops stale selections" {
+    printf '[AppA]\nbuild=echo a\ntest=echo t\n' > "$BATS_TEST_TMPDIR/reload.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        SELECTED_ITEMS=("AppA - build" "AppA - test")
+        printf "[AppA]\nbuild=echo a2\n[AppB]\nrun=echo b\n" > "$CONFIG_FILE"
+        reload_config || exit 1
+        echo "apps:${APPS[*]} build:${APP_ACTIONS[AppA:build]} selected:${SELECTED_ITEMS[*]}"
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/reload.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" == "apps:AppA AppB build:echo a2 selected:AppA - build" ]]
+}
+
+@test "Reloading a broken config keeps the current one" {
+    printf '[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/reload.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        printf "[AppA]\ntimeout=soon\n" > "$CONFIG_FILE"
+        reload_config && exit 1
+        echo "apps:${APPS[*]} build:${APP_ACTIONS[AppA:build]} error:$RELOAD_ERROR"
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/reload.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "apps:AppA build:echo a error:Invalid timeout 'soon'" ]]
+}
+
+@test "Theme keys override the default menu colors" {
+    printf 'theme.highlight = bold #ff8800\ntheme.dim = 244\n[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/theme.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        echo "highlight:${THEME[highlight]} dim:${THEME[dim]} filter:${THEME[filter]}"
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/theme.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" == 'highlight:\033[1m\033[38;2;255;136;0m dim:\033[38;5;244m filter:\033[1;33m' ]]
+}
+
+@test "Error on invalid theme color or role" {
+    printf 'theme.highlight=pink\n[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/theme.cfg"
+    run "$SHELL_BUN" --ci AppA build "$BATS_TEST_TMPDIR/theme.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Invalid theme color 'pink' for 'theme.highlight' on line 1" ]]
+
+    printf 'theme.cursor=red\n[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/theme.cfg"
+    run "$SHELL_BUN" --ci AppA build "$BATS_TEST_TMPDIR/theme.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Unknown theme role 'cursor'" ]]
+}
+
+@test "Group sections collect member apps" {
+    printf '[group:backend]\nmembers = API, Worker\n[Web]\nbuild=echo w\n[API]\nbuild=echo a\n[Worker]\nbuild=echo k\n' > "$BATS_TEST_TMPDIR/groups.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        echo "apps:${APPS[*]} groups:${GROUP_NAMES[*]} members:${GROUP_MEMBERS[backend]} api:${APP_GROUP[API]}"
+        build_menu_items
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/groups.cfg"
+    [ "$status" -eq 0 ]
+    [[ "${lines[0]}" == "apps:Web API Worker groups:backend members:API,Worker api:backend" ]]
+    [[ "${lines[3]}" == "Group: backend (2 apps)" ]]
+    [[ "${lines[4]}" == "API - build" ]]
+}
+
+@test "Error on unknown group member or key" {
+    printf '[group:backend]\nmembers=Nope\n[API]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/groups.cfg"
+    run "$SHELL_BUN" --ci API build "$BATS_TEST_TMPDIR/groups.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Group 'backend' in '$BATS_TEST_TMPDIR/groups.cfg' lists unknown app 'Nope'" ]]
+
+    printf '[group:backend]\nbuild=echo a\n[API]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/groups.cfg"
+    run "$SHELL_BUN" --ci API build "$BATS_TEST_TMPDIR/groups.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Unknown key 'build' in [group:backend] on line 2" ]]
+}
+
+@test "Shell key selects the shell that runs commands" {
+    printf 'shell=sh\n[Host]\nwho=echo "running in $0"\n[Zsh]\nshell=zsh\nwho=echo hi\n' > "$BATS_TEST_TMPDIR/shell.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        build_full_command Host who
+        build_full_command Zsh who
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/shell.cfg"
+    [ "$status" -eq 0 ]
+    [[ "${lines[0]}" == 'sh -c echo\ \"running\ in\ \$0\"' ]]
+    [[ "${lines[1]}" == 'zsh -c echo\ hi' ]]
+
+    run bash "$SHELL_BUN" --ci Host who "$BATS_TEST_TMPDIR/shell.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "running in sh" ]]
+}
+
+@test "Container commands use login mode only for shells that support it" {
+    printf 'container=docker run --rm img\n[Login]\nshell=zsh\nbuild=make\n[Plain]\nshell=dash\nbuild=make\n' > "$BATS_TEST_TMPDIR/shell.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        build_full_command Login build
+        build_full_command Plain build
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/shell.cfg"
+    [ "$status" -eq 0 ]
+    [[ "${lines[0]}" == "docker run --rm img zsh -lc make" ]]
+    [[ "${lines[1]}" == "docker run --rm img dash -c make" ]]
+}
+
+@test "Error when the configured shell is not installed" {
+    printf '[TestApp]\nshell=no-such-shell-bun-shell\nbuild=echo b\n' > "$BATS_TEST_TMPDIR/shell.cfg"
+    run bash "$SHELL_BUN" --ci TestApp build "$BATS_TEST_TMPDIR/shell.cfg"
+    [ "$status" -eq 4 ]
+    [[ "$output" =~ "Shell 'no-such-shell-bun-shell' for TestApp was not found on PATH" ]]
+}
+
+@test "Include merges apps from files relative to the including file" {
+    mkdir -p "$BATS_TEST_TMPDIR/conf/sub"
+    printf 'include = conf/backend.cfg\n[Frontend]\nbuild=echo front\n' > "$BATS_TEST_TMPDIR/main.cfg"
+    printf 'include = sub/db.cfg\n[Backend]\nbuild=echo back\n[Frontend]\ntest=echo front-test\n' > "$BATS_TEST_TMPDIR/conf/backend.cfg"
+    printf '[Database]\nbuild=echo db\n' > "$BATS_TEST_TMPDIR/conf/sub/db.cfg"
+    run bash "$SHELL_BUN" --list "$BATS_TEST_TMPDIR/main.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ Frontend$'\t'build ]]
+    [[ "$output" =~ Frontend$'\t'test ]]
+    [[ "$output" =~ Backend$'\t'build ]]
+    [[ "$output" =~ Database$'\t'build ]]
+}
+
+@test "Globals in an included file do not override the main file" {
+    printf 'timeout=7\ninclude=extra.cfg\n[TestApp]\nbuild=echo b\n' > "$BATS_TEST_TMPDIR/main.cfg"
+    printf 'timeout=99\nshell=sh\n' > "$BATS_TEST_TMPDIR/extra.cfg"
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        echo "timeout:$GLOBAL_TIMEOUT shell:$GLOBAL_SHELL"
+    ' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/main.cfg"
+    [ "$status" -eq 0 ]
+    [[ "${lines[0]}" == "timeout:7 shell:sh" ]]
+}
+
+@test "Error on circular include" {
+    printf 'include=b.cfg\n[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/a.cfg"
+    printf 'include=a.cfg\n[AppB]\nbuild=echo b\n' > "$BATS_TEST_TMPDIR/b.cfg"
+    run bash "$SHELL_BUN" --list "$BATS_TEST_TMPDIR/a.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Circular include" ]]
+    [[ "$output" =~ "/a.cfg -> $BATS_TEST_TMPDIR/b.cfg -> $BATS_TEST_TMPDIR/a.cfg" ]]
+
+    printf 'include=missing.cfg\n[AppA]\nbuild=echo a\n' > "$BATS_TEST_TMPDIR/c.cfg"
+    run bash "$SHELL_BUN" --list "$BATS_TEST_TMPDIR/c.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "missing.cfg' not found" ]]
+}
@@ -98,6 +98,48 @@ CONFIG
     [[ "$output" != *"Detected $CONTAINER_ENV_PATH - ignoring configured container command"* ]]
 }
 
+@test "per-app container takes precedence over --container and the global value" {
+    cat > "$TEST_CONFIG" <<'CONFIG'
+# Test config to ensure an app's own container key wins
+container=env CONTAINER_SOURCE=config
+
+[AppContainer]
+container=env CONTAINER_SOURCE=app
+build=echo "AppContainer source: ${CONTAINER_SOURCE:-none}"
+
+[GlobalContainer]
+build=echo "GlobalContainer source: ${CONTAINER_SOURCE:-none}"
+CONFIG
+
+    run "$SCRIPT_DIR/shell-bun.sh" --ci "*Container" build "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" == *"AppContainer source: app"* ]]
+    [[ "$output" == *"GlobalContainer source: config"* ]]
+
+    run "$SCRIPT_DIR/shell-bun.sh" --container "env CONTAINER_SOURCE=cli" --ci "*Container" build "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" == *"AppContainer source: app"* ]]
+    [[ "$output" == *"GlobalContainer source: cli"* ]]
+}
+
+@test "empty per-app container runs that app on the host" {
+    cat > "$TEST_CONFIG" <<'CONFIG'
+container=/bin/false
+
+[TestApp]
+container=
+build=echo host-run
+CONFIG
+
+    run "$SCRIPT_DIR/shell-bun.sh" --ci TestApp build "$TEST_CONFIG"
+
+    echo "Exit code: $status"
+    echo "Output: $output"
+
+    [ "$status" -eq 0 ]
+    [[ "$output" == *"host-run"* ]]
+}
+
 teardown() {
     if [ -f "$TEST_CONFIG" ]; then
         rm "$TEST_CONFIG"
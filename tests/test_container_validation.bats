@@ -0,0 +1,69 @@
+#!/usr/bin/env bats
+
+# Test container command validation
+
+setup() {
+    SCRIPT_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)"
+    SHELL_BUN="$SCRIPT_DIR/shell-bun.sh"
+    TEST_CONFIG="$BATS_TEST_TMPDIR/container_validation.cfg"
+}
+
+teardown() {
+    rm -f "$TEST_CONFIG"
+}
+
+@test "Container command with unbalanced quotes is rejected" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+container=env FOO="unterminated
+
+[TestApp]
+build=echo "should not run"
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 4 ]
+    [[ "$output" =~ "syntax error" ]]
+    [[ ! "$output" =~ $'\n'"should not run" ]]
+}
+
+@test "Container runtime missing from PATH is rejected" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+container=definitely-not-a-runtime run --rm image
+
+[TestApp]
+build=echo "should not run"
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 4 ]
+    [[ "$output" =~ "Container runtime 'definitely-not-a-runtime' not found" ]]
+}
+
+@test "Unknown container runtime produces a warning but still runs" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+container=env WRAPPED=yes
+
+[TestApp]
+build=echo "wrapped: $WRAPPED"
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "'env' is not a known container runtime" ]]
+    [[ "$output" =~ "wrapped: yes" ]]
+}
+
+@test "known_runtimes silences the unknown runtime warning" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+known_runtimes=env,my-wrapper
+container=env WRAPPED=yes
+
+[TestApp]
+build=echo "wrapped: $WRAPPED"
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ ! "$output" =~ "not a known container runtime" ]]
+    [[ "$output" =~ "wrapped: yes" ]]
+}
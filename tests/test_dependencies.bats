@@ -0,0 +1,60 @@
+#!/usr/bin/env bats
+
+# Test action dependencies (ACTION.depends)
+
+setup() {
+    SCRIPT_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)"
+    SHELL_BUN="$SCRIPT_DIR/shell-bun.sh"
+    TEST_CONFIG="$BATS_TEST_TMPDIR/depends.cfg"
+}
+
+@test "Dependencies run first, in order and only once" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+[TestApp]
+clean=echo step-clean
+setup=echo step-setup
+setup.depends=clean
+build=echo step-build
+build.depends=clean, setup
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [ "$(echo "$output" | grep '^step-' | tr '\n' ' ')" = "step-clean step-setup step-build " ]
+}
+
+@test "A failing dependency stops the action" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+[TestApp]
+prepare=exit 3
+build=echo "should not run"
+build.depends=prepare
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 4 ]
+    [[ "$output" =~ "Dependency 'prepare' of TestApp - build failed" ]]
+    [[ ! "$output" =~ "should not run" ]]
+}
+
+@test "Error on unknown dependency" {
+    printf '[TestApp]\nbuild=echo b\nbuild.depends=missing\n' > "$TEST_CONFIG"
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Unknown dependency 'missing' for 'TestApp:build' on line 3" ]]
+}
+
+@test "Error on dependency cycle" {
+    printf '[TestApp]\na=echo a\nb=echo b\na.depends=b\nb.depends=a\n' > "$TEST_CONFIG"
+    run bash "$SHELL_BUN" --ci TestApp a "$TEST_CONFIG"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Dependency cycle in TestApp on line 4" ]]
+    [[ "$output" =~ "a -> b -> a" ]]
+}
+
+@test "Show Details lists the dependency chain" {
+    printf '[TestApp]\nclean=echo c\nbuild=echo b\nbuild.depends=clean\n' > "$TEST_CONFIG"
+    run bash -c 'source "$1"; CONFIG_FILE="$2"; parse_config; show_app_details TestApp' bash "$SHELL_BUN" "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Depends: clean -> build" ]]
+}
@@ -0,0 +1,39 @@
+#!/usr/bin/env bats
+
+# Test sourcing shell-bun.sh as a library
+
+setup() {
+    SCRIPT_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)"
+    SHELL_BUN="$SCRIPT_DIR/shell-bun.sh"
+    TEST_FIXTURES="$SCRIPT_DIR/tests/fixtures"
+}
+
+@test "Sourcing the script does not start the menu or parse arguments" {
+    run bash -c 'script="$1"; set -- --ci; source "$script"; echo "sourced ok"' bash "$SHELL_BUN"
+    [ "$status" -eq 0 ]
+    [[ "$output" == "sourced ok" ]]
+}
+
+@test "Sourced functions load a config and match patterns" {
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        match_apps_fuzzy "Test*"
+        match_actions_fuzzy "b*" TestApp2
+    ' bash "$SHELL_BUN" "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" == *$'TestApp1\nTestApp2\nbuild'* ]]
+}
+
+@test "Sourced execute_command runs an action" {
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        CI_MODE=1
+        parse_config
+        execute_command TestApp1 build
+    ' bash "$SHELL_BUN" "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building TestApp1" ]]
+}
@@ -68,3 +68,15 @@ EOF
     rm -rf "$SCRIPT_DIR/relative_logs"
 }
 
+
+@test "log_name_template controls the log file name" {
+    printf 'log_dir=%s\nlog_name_template={app}-{action}-{date}.log\n[TestApp]\ntest=echo "Test"\n' "$BATS_TEST_TMPDIR/logs" > "$BATS_TEST_TMPDIR/template.cfg"
+    run bash "$SHELL_BUN" --ci TestApp test --json "$BATS_TEST_TMPDIR/template.cfg"
+    [ "$status" -eq 0 ]
+    [ -f "$BATS_TEST_TMPDIR/logs/TestApp-test-$(date '+%Y-%m-%d').log" ]
+
+    printf 'log_name_template={app}-{host}.log\n[TestApp]\ntest=echo "Test"\n' > "$BATS_TEST_TMPDIR/bad.cfg"
+    run bash "$SHELL_BUN" --ci TestApp test "$BATS_TEST_TMPDIR/bad.cfg"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Invalid log_name_template '{app}-{host}.log' on line 1" ]]
+}
@@ -73,3 +73,38 @@ setup() {
     [[ "$output" =~ "clean" ]]
 }
 
+
+@test "Regex app pattern rejects a name that substring matching would accept" {
+    run bash "$SHELL_BUN" --ci "/^App1/" build "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 2 ]
+    [[ "$output" =~ "No applications found" ]]
+}
+
+@test "Regex app pattern matches a name that substring matching would miss" {
+    run bash "$SHELL_BUN" --ci "/^Test.*2$/" build "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building TestApp2" ]]
+    [[ ! "$output" =~ "Building TestApp1" ]]
+}
+
+@test "Regex action pattern may contain commas" {
+    run bash "$SHELL_BUN" --ci TestApp2 "/^(bu|de).{2,4}$/,missing" "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building TestApp2" ]]
+    [[ "$output" =~ "Deploying TestApp2" ]]
+}
+
+@test "Invalid regex pattern is reported" {
+    run bash "$SHELL_BUN" --ci "/Test(/" build "$TEST_FIXTURES/basic.cfg"
+    [ "$status" -eq 2 ]
+    [[ "$output" =~ "Invalid regular expression in pattern '/Test(/'" ]]
+}
+
+@test "Group name expands to its member apps" {
+    printf '[group:backend]\nmembers=API,Worker\n[API]\nbuild=echo "Building API"\n[Worker]\nbuild=echo "Building Worker"\n[APIDocs]\nbuild=echo "Building APIDocs"\n' > "$BATS_TEST_TMPDIR/groups.cfg"
+    run bash "$SHELL_BUN" --ci backend build "$BATS_TEST_TMPDIR/groups.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Building API" ]]
+    [[ "$output" =~ "Building Worker" ]]
+    [[ ! "$output" =~ "Building APIDocs" ]]
+}
@@ -0,0 +1,55 @@
+#!/usr/bin/env bats
+
+# Test the saved selection (.shell-bun-session)
+
+setup() {
+    SCRIPT_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)"
+    SHELL_BUN="$SCRIPT_DIR/shell-bun.sh"
+    TEST_CONFIG="$BATS_TEST_TMPDIR/session.cfg"
+    printf '[AppA]\nbuild=echo a\ntest=echo t\n[AppB]\nrun=echo b\n' > "$TEST_CONFIG"
+    cd "$BATS_TEST_TMPDIR"
+}
+
+@test "Saved selection is restored for the same config" {
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        SELECTED_ITEMS=("AppA - test" "AppB - run")
+        save_session
+        SELECTED_ITEMS=()
+        load_session_items
+    ' bash "$SHELL_BUN" "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "${lines[0]}" == "AppA - test" ]]
+    [[ "${lines[1]}" == "AppB - run" ]]
+    grep -q "^selected=AppA:test$" "$BATS_TEST_TMPDIR/.shell-bun-session"
+}
+
+@test "Saved selection is ignored after the config changed" {
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        SELECTED_ITEMS=("AppA - build")
+        save_session
+        echo "extra=echo e" >> "$CONFIG_FILE"
+        load_session_items || echo "no session"
+    ' bash "$SHELL_BUN" "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" == "no session" ]]
+}
+
+@test "Clearing the selection removes the session file" {
+    run bash -c '
+        source "$1"
+        CONFIG_FILE="$2"
+        parse_config
+        SELECTED_ITEMS=("AppA - build")
+        save_session
+        SELECTED_ITEMS=()
+        save_session
+    ' bash "$SHELL_BUN" "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [ ! -f "$BATS_TEST_TMPDIR/.shell-bun-session" ]
+}
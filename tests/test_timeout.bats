@@ -0,0 +1,66 @@
+#!/usr/bin/env bats
+
+# Test per-action execution timeouts
+
+setup() {
+    SCRIPT_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)"
+    SHELL_BUN="$SCRIPT_DIR/shell-bun.sh"
+    TEST_CONFIG="$BATS_TEST_TMPDIR/timeout.cfg"
+}
+
+@test "Global timeout stops a hanging action with exit code 124" {
+    cat > "$TEST_CONFIG" << EOF2
+timeout=1
+log_dir=$BATS_TEST_TMPDIR/logs
+
+[TestApp]
+hang=echo started; sleep 10; echo "should not finish"
+EOF2
+
+    SECONDS=0
+    run bash "$SHELL_BUN" --ci TestApp hang --json "$TEST_CONFIG"
+    [ "$status" -eq 124 ]
+    [ "$SECONDS" -lt 8 ]
+    [[ "$output" =~ "Timed out after 1s: TestApp - hang (exit code 124)" ]]
+    [[ "$output" =~ \"exit_code\":\ 124 ]]
+    [[ ! "$output" =~ "should not finish" ]]
+    grep -q "Timed out after 1s" "$BATS_TEST_TMPDIR"/logs/*_TestApp_hang.log
+}
+
+@test "Per-app timeout overrides the global value" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+timeout=1
+
+[SlowApp]
+timeout=0
+build=sleep 1.5; echo "slow build done"
+
+[FastApp]
+build=echo "fast build done"
+EOF2
+
+    run bash "$SHELL_BUN" --ci "SlowApp,FastApp" build --output table "$TEST_CONFIG"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "slow build done" ]]
+    [[ "$output" =~ "fast build done" ]]
+    [[ ! "$output" =~ "TIMEOUT" ]]
+}
+
+@test "Timed out actions are reported as TIMEOUT in the results table" {
+    cat > "$TEST_CONFIG" << 'EOF2'
+[TestApp]
+timeout=1
+hang=sleep 10
+EOF2
+
+    run bash "$SHELL_BUN" --ci TestApp hang --output table "$TEST_CONFIG"
+    [ "$status" -eq 124 ]
+    [[ "$output" =~ hang\ +.*TIMEOUT ]]
+}
+
+@test "Error on a timeout that is not whole seconds" {
+    printf 'timeout=5m\n[TestApp]\nbuild=echo ok\n' > "$TEST_CONFIG"
+    run bash "$SHELL_BUN" --ci TestApp build "$TEST_CONFIG"
+    [ "$status" -eq 1 ]
+    [[ "$output" =~ "Invalid timeout '5m' on line 1" ]]
+}
@@ -31,7 +31,7 @@ test=echo "Should not run"
 EOF
     
     run bash "$SHELL_BUN" --ci TestApp test /tmp/test_nonexistent.cfg
-    [ "$status" -eq 1 ]
+    [ "$status" -eq 4 ]
     [[ "$output" =~ "Working directory" ]] && [[ "$output" =~ "does not exist" ]]
     
     rm -f /tmp/test_nonexistent.cfg
@@ -63,3 +63,50 @@ EOF
     rm -f /tmp/test_tilde.cfg
 }
 
+
+@test "path_relative_to_config resolves working_dir against the config directory" {
+    mkdir -p /tmp/test1/configs/sub
+    cat > /tmp/test1/configs/relative.cfg << 'EOF2'
+path_relative_to_config=true
+
+[DotApp]
+working_dir=.
+test=pwd
+
+[SubApp]
+working_dir=sub
+test=pwd
+EOF2
+    
+    run bash "$SHELL_BUN" --ci DotApp test /tmp/test1/configs/relative.cfg
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ $'\n'"/tmp/test1/configs"$'\n' ]]
+    
+    run bash "$SHELL_BUN" --ci SubApp test /tmp/test1/configs/relative.cfg
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "/tmp/test1/configs/sub" ]]
+}
+
+@test "working_dir stays relative to the script without path_relative_to_config" {
+    mkdir -p /tmp/test1/configs
+    cat > /tmp/test1/configs/default.cfg << 'EOF2'
+[DotApp]
+working_dir=.
+test=pwd
+EOF2
+    
+    run bash "$SHELL_BUN" --ci DotApp test /tmp/test1/configs/default.cfg
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ $'\n'"$SCRIPT_DIR"$'\n' ]]
+    [[ ! "$output" =~ $'\n'"/tmp/test1/configs"$'\n' ]]
+}
+
+@test "Show Details prints the resolved working directory and warns when it is missing" {
+    mkdir -p "$BATS_TEST_TMPDIR/project/sub"
+    printf 'path_relative_to_config=true\n[Here]\nworking_dir=./project/sub/..\ntest=pwd\n[Gone]\nworking_dir=./missing\ntest=pwd\n' > "$BATS_TEST_TMPDIR/details.cfg"
+    run bash -c 'source "$1"; CONFIG_FILE="$2"; parse_config; show_app_details Here; show_app_details Gone' bash "$SHELL_BUN" "$BATS_TEST_TMPDIR/details.cfg"
+    [ "$status" -eq 0 ]
+    [[ "$output" =~ "Working Dir:    $BATS_TEST_TMPDIR/project (configured: ./project/sub/..)" ]]
+    [[ "$output" =~ "Working Dir:    $BATS_TEST_TMPDIR/missing (configured: ./missing)" ]]
+    [[ "$output" =~ "Warning: this directory does not exist" ]]
+}